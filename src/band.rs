@@ -0,0 +1,322 @@
+use super::convert::*;
+use super::*;
+
+/// A scale that maps a list of categories to evenly spaced bands, e.g. for the bars of a bar chart,
+/// modelled after d3's band scale.
+///
+/// All positions and sizes are relative values between 0.0 and 1.0, so any [`Scale`] can be used to
+/// position the bands, e.g. a [`LinearScale`](crate::prelude::LinearScale) for a range of pixels.
+/// `padding_inner` is the fraction of a step reserved for the gap between two bands, `padding_outer` is the
+/// gap before the first and after the last band in multiples of a step, and `align` distributes any remaining
+/// space before (0.0) or after (1.0) the bands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandScale<T> {
+    domain: Vec<T>,
+    padding_inner: f64,
+    padding_outer: f64,
+    align: f64,
+}
+
+impl<T> BandScale<T>
+where
+    T: PartialEq,
+{
+    /// Create a band scale without any padding and centered alignment.
+    pub fn new(domain: Vec<T>) -> BandScale<T> {
+        BandScale {
+            domain,
+            padding_inner: 0.0,
+            padding_outer: 0.0,
+            align: 0.5,
+        }
+    }
+
+    /// Set the inner padding, clamped to 0.0..1.0.
+    pub fn with_padding_inner(mut self, padding_inner: f64) -> BandScale<T> {
+        self.padding_inner = padding_inner.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the outer padding, which must not be negative.
+    pub fn with_padding_outer(mut self, padding_outer: f64) -> BandScale<T> {
+        self.padding_outer = padding_outer.max(0.0);
+        self
+    }
+
+    /// Set both inner and outer padding to the same value.
+    pub fn with_padding(self, padding: f64) -> BandScale<T> {
+        self.with_padding_inner(padding).with_padding_outer(padding)
+    }
+
+    /// Set the alignment, clamped to 0.0..1.0.
+    pub fn with_align(mut self, align: f64) -> BandScale<T> {
+        self.align = align.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn domain(&self) -> &[T] {
+        &self.domain
+    }
+
+    pub fn padding_inner(&self) -> f64 {
+        self.padding_inner
+    }
+
+    pub fn padding_outer(&self) -> f64 {
+        self.padding_outer
+    }
+
+    pub fn align(&self) -> f64 {
+        self.align
+    }
+
+    /// The relative distance between the starts of two adjacent bands.
+    pub fn step(&self) -> f64 {
+        let n = self.domain.len() as f64;
+        1.0 / (n - self.padding_inner + 2.0 * self.padding_outer).max(1.0)
+    }
+
+    /// The relative width of each band.
+    pub fn bandwidth(&self) -> f64 {
+        self.step() * (1.0 - self.padding_inner)
+    }
+
+    pub fn index_of(&self, value: &T) -> Option<usize> {
+        self.domain.iter().position(|v| v == value)
+    }
+
+    /// The relative start of the band of a value, `None` if the value is not part of the domain.
+    pub fn to_relative(&self, value: &T) -> Option<f64> {
+        self.index_of(value)
+            .map(|i| self.start() + i as f64 * self.step())
+    }
+
+    /// The relative start and end of the band of a value, `None` if the value is not part of the domain.
+    pub fn band(&self, value: &T) -> Option<(f64, f64)> {
+        self.to_relative(value)
+            .map(|start| (start, start + self.bandwidth()))
+    }
+
+    /// The value whose band contains a relative position, `None` if the position lies in a gap or outside all bands.
+    pub fn value_at(&self, relative: f64) -> Option<&T> {
+        let offset = relative - self.start();
+        if !offset.is_finite() || offset < 0.0 {
+            return None;
+        }
+        let index = (offset / self.step()).floor();
+        if !index.is_finite() || offset - index * self.step() > self.bandwidth() {
+            return None;
+        }
+        self.domain.get(index as usize)
+    }
+
+    /// The absolute start of the band of a value within a range, `None` if the value is not part of the domain.
+    pub fn position<N, S>(&self, value: &T, range: &S) -> Option<N>
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        S: Scale<N>,
+    {
+        self.to_relative(value).map(|r| range.to_absolute(r))
+    }
+
+    /// The absolute start and end of the band of a value within a range, `None` if the value is not part of the domain.
+    pub fn band_in<N, S>(&self, value: &T, range: &S) -> Option<(N, N)>
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        S: Scale<N>,
+    {
+        self.band(value)
+            .map(|(start, end)| (range.to_absolute(start), range.to_absolute(end)))
+    }
+
+    /// The absolute width of each band within a range.
+    pub fn bandwidth_in<N, S>(&self, range: &S) -> N
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        S: Scale<N>,
+    {
+        range.to_absolute_delta(self.bandwidth(), range.to_absolute(0.0))
+    }
+
+    /// The absolute distance between the starts of two adjacent bands within a range.
+    pub fn step_in<N, S>(&self, range: &S) -> N
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        S: Scale<N>,
+    {
+        range.to_absolute_delta(self.step(), range.to_absolute(0.0))
+    }
+
+    fn start(&self) -> f64 {
+        let n = self.domain.len() as f64;
+        (1.0 - self.step() * (n - self.padding_inner)) * self.align
+    }
+}
+
+/// A scale that maps a list of categories to evenly spaced points, e.g. for a scatter plot with a
+/// categorical axis, modelled after d3's point scale. This is a [`BandScale`] whose bands have zero width.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointScale<T> {
+    band: BandScale<T>,
+}
+
+impl<T> PointScale<T>
+where
+    T: PartialEq,
+{
+    /// Create a point scale without any padding and centered alignment.
+    pub fn new(domain: Vec<T>) -> PointScale<T> {
+        PointScale {
+            band: BandScale::new(domain).with_padding_inner(1.0),
+        }
+    }
+
+    /// Set the padding before the first and after the last point in multiples of a step.
+    pub fn with_padding(self, padding: f64) -> PointScale<T> {
+        PointScale {
+            band: self.band.with_padding_outer(padding),
+        }
+    }
+
+    /// Set the alignment, clamped to 0.0..1.0.
+    pub fn with_align(self, align: f64) -> PointScale<T> {
+        PointScale {
+            band: self.band.with_align(align),
+        }
+    }
+
+    pub fn domain(&self) -> &[T] {
+        self.band.domain()
+    }
+
+    pub fn padding(&self) -> f64 {
+        self.band.padding_outer()
+    }
+
+    pub fn align(&self) -> f64 {
+        self.band.align()
+    }
+
+    /// The relative distance between two adjacent points.
+    pub fn step(&self) -> f64 {
+        self.band.step()
+    }
+
+    /// The relative position of a value, `None` if the value is not part of the domain.
+    pub fn to_relative(&self, value: &T) -> Option<f64> {
+        self.band.to_relative(value)
+    }
+
+    /// The absolute position of a value within a range, `None` if the value is not part of the domain.
+    pub fn position<N, S>(&self, value: &T, range: &S) -> Option<N>
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        S: Scale<N>,
+    {
+        self.band.position(value, range)
+    }
+
+    /// The absolute distance between two adjacent points within a range.
+    pub fn step_in<N, S>(&self, range: &S) -> N
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        S: Scale<N>,
+    {
+        self.band.step_in(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_band() {
+        let scale = BandScale::new(vec!["a", "b", "c"]);
+
+        assert_approx_eq!(scale.step(), 1.0 / 3.0);
+        assert_approx_eq!(scale.bandwidth(), 1.0 / 3.0);
+        assert_approx_eq!(scale.to_relative(&"a").unwrap(), 0.0);
+        assert_approx_eq!(scale.to_relative(&"b").unwrap(), 1.0 / 3.0);
+        assert_approx_eq!(scale.band(&"c").unwrap().1, 1.0);
+        assert_eq!(scale.to_relative(&"d"), None);
+    }
+
+    #[test]
+    fn test_band_padding_and_align() {
+        let scale = BandScale::new(vec!["a", "b", "c"])
+            .with_padding_inner(0.1)
+            .with_padding_outer(0.2);
+
+        let step = 1.0 / 3.3;
+        let start = (1.0 - step * 2.9) / 2.0;
+        assert_approx_eq!(scale.step(), step);
+        assert_approx_eq!(scale.bandwidth(), step * 0.9);
+        assert_approx_eq!(scale.to_relative(&"a").unwrap(), start);
+        assert_approx_eq!(scale.to_relative(&"c").unwrap(), start + 2.0 * step);
+        assert_approx_eq!(scale.band(&"c").unwrap().1, 1.0 - start);
+
+        let left = scale.clone().with_align(0.0);
+        assert_approx_eq!(left.to_relative(&"a").unwrap(), 0.0);
+        let right = scale.with_align(1.0);
+        assert_approx_eq!(right.band(&"c").unwrap().1, 1.0);
+
+        let clamped = BandScale::new(vec![1, 2])
+            .with_padding(2.0)
+            .with_align(-1.0);
+        assert_approx_eq!(clamped.padding_inner(), 1.0);
+        assert_approx_eq!(clamped.padding_outer(), 2.0);
+        assert_approx_eq!(clamped.align(), 0.0);
+    }
+
+    #[test]
+    fn test_band_value_at() {
+        let scale = BandScale::new(vec!["a", "b"]).with_padding(0.5);
+
+        // step = 1 / 2.5, bandwidth = 0.2, bands at 0.2..0.4 and 0.6..0.8
+        assert_eq!(scale.value_at(0.1), None);
+        assert_eq!(scale.value_at(0.3), Some(&"a"));
+        assert_eq!(scale.value_at(0.5), None);
+        assert_eq!(scale.value_at(0.7), Some(&"b"));
+        assert_eq!(scale.value_at(0.9), None);
+        assert_eq!(scale.value_at(f64::NAN), None);
+        assert_eq!(scale.value_at(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_band_pixels() {
+        let scale = BandScale::new(vec!["a", "b", "c"]);
+        let pixels: LinearScale<f64> = LinearScale::new(0.0, 300.0);
+
+        assert_approx_eq!(scale.position(&"b", &pixels).unwrap(), 100.0);
+        assert_approx_eq!(scale.bandwidth_in(&pixels), 100.0);
+        assert_approx_eq!(scale.step_in(&pixels), 100.0);
+        let (start, end) = scale.band_in(&"c", &pixels).unwrap();
+        assert_approx_eq!(start, 200.0);
+        assert_approx_eq!(end, 300.0);
+
+        let y_axis: LinearScale<f64> = LinearScale::new(300.0, 0.0);
+        assert_approx_eq!(scale.position(&"a", &y_axis).unwrap(), 300.0);
+        assert_approx_eq!(scale.bandwidth_in(&y_axis), -100.0);
+    }
+
+    #[test]
+    fn test_point() {
+        let scale = PointScale::new(vec!["a", "b", "c"]);
+        assert_approx_eq!(scale.step(), 0.5);
+        assert_approx_eq!(scale.to_relative(&"a").unwrap(), 0.0);
+        assert_approx_eq!(scale.to_relative(&"b").unwrap(), 0.5);
+        assert_approx_eq!(scale.to_relative(&"c").unwrap(), 1.0);
+
+        let padded = scale.with_padding(0.5);
+        assert_approx_eq!(padded.step(), 1.0 / 3.0);
+        assert_approx_eq!(padded.to_relative(&"a").unwrap(), 1.0 / 6.0);
+        assert_approx_eq!(padded.to_relative(&"c").unwrap(), 5.0 / 6.0);
+
+        let pixels: LinearScale<f64> = LinearScale::new(0.0, 600.0);
+        assert_approx_eq!(padded.position(&"b", &pixels).unwrap(), 300.0);
+        assert_approx_eq!(padded.step_in(&pixels), 200.0);
+    }
+}
@@ -1,5 +1,6 @@
 use super::convert::*;
 use super::*;
+use crate::inverted::*;
 use crate::linear::*;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -24,6 +25,10 @@ where
         BrokenScale { delegate, steps }
     }
 
+    pub fn inverted(min: N, max: N, steps: &[(N, f64)]) -> Inverted<BrokenScale<N>> {
+        Inverted::new(BrokenScale::new(min, max, steps))
+    }
+
     fn broken_y(&self, rel_x: f64) -> f64 {
         let mut from = (0.0, 0.0);
         let mut to = (1.0, 1.0);
@@ -97,7 +102,7 @@ where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
 {
     fn to_relative(&self, absolute: N) -> f64 {
-        let delegated_relative = dbg!(self.delegate.to_relative(absolute));
+        let delegated_relative = self.delegate.to_relative(absolute);
         self.broken_y(delegated_relative)
     }
 
@@ -115,6 +120,177 @@ where
     }
 }
 
+/// A variant of [`BrokenScale`] that interpolates between its steps with a monotone cubic spline
+/// (Fritsch–Carlson) instead of straight lines, so the curve has no kinks at the steps.
+///
+/// The curve always runs from (`min`, 0.0) to (`max`, 1.0). Steps at or beyond the minimum or maximum and steps
+/// that do not lie beyond the previous step are ignored. Steps whose relative value is lower than that of the
+/// previous step or higher than 1.0 are ignored as well, so the curve stays monotonic and thus invertible.
+/// Outside of the scale's range the curve is extended linearly with the slope at the respective end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmoothBrokenScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    delegate: LinearScale<N>,
+    knots: Vec<(f64, f64)>,
+    tangents: Vec<f64>,
+}
+
+impl<N> SmoothBrokenScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N, steps: &[(N, f64)]) -> SmoothBrokenScale<N> {
+        let delegate = LinearScale::new(min, max);
+        let mut knots = vec![(0.0, 0.0)];
+        for (abs, rel) in steps {
+            let x = delegate.to_relative(abs.clone());
+            let (previous_x, previous_y) = knots[knots.len() - 1];
+            if x > previous_x && x < 1.0 && *rel >= previous_y && *rel <= 1.0 {
+                knots.push((x, *rel));
+            }
+        }
+        knots.push((1.0, 1.0));
+        let tangents = monotone_tangents(&knots);
+
+        SmoothBrokenScale {
+            delegate,
+            knots,
+            tangents,
+        }
+    }
+
+    fn segment_of(&self, rel_x: f64) -> usize {
+        let last = self.knots.len() - 2;
+        self.knots[1..=last]
+            .iter()
+            .position(|(x, _)| rel_x < *x)
+            .unwrap_or(last)
+    }
+
+    fn smooth_y(&self, rel_x: f64) -> f64 {
+        let last = self.knots.len() - 1;
+        if rel_x < 0.0 {
+            return rel_x * self.tangents[0];
+        }
+        if rel_x > 1.0 {
+            return 1.0 + (rel_x - 1.0) * self.tangents[last];
+        }
+
+        self.hermite(self.segment_of(rel_x), rel_x)
+    }
+
+    fn smooth_x(&self, rel_y: f64) -> f64 {
+        let last = self.knots.len() - 1;
+        if rel_y < 0.0 {
+            return rel_y / self.tangents[0];
+        }
+        if rel_y > 1.0 {
+            return 1.0 + (rel_y - 1.0) / self.tangents[last];
+        }
+
+        let segment = self.knots[1..last]
+            .iter()
+            .position(|(_, y)| rel_y <= *y)
+            .unwrap_or(last - 1);
+        let (x0, y0) = self.knots[segment];
+        let (x1, y1) = self.knots[segment + 1];
+        if y1 == y0 {
+            return x0;
+        }
+
+        numeric::invert_monotonic(
+            |x| self.hermite(segment, x),
+            rel_y,
+            x0,
+            x1,
+            INVERSION_TOLERANCE,
+            INVERSION_MAX_ITERATIONS,
+        )
+        .unwrap_or(x0 + (rel_y - y0) / (y1 - y0) * (x1 - x0))
+    }
+
+    fn hermite(&self, segment: usize, rel_x: f64) -> f64 {
+        let (x0, y0) = self.knots[segment];
+        let (x1, y1) = self.knots[segment + 1];
+        let m0 = self.tangents[segment];
+        let m1 = self.tangents[segment + 1];
+
+        let h = x1 - x0;
+        let t = (rel_x - x0) / h;
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+            + (t3 - 2.0 * t2 + t) * h * m0
+            + (-2.0 * t3 + 3.0 * t2) * y1
+            + (t3 - t2) * h * m1
+    }
+}
+
+const INVERSION_TOLERANCE: f64 = 1e-12;
+const INVERSION_MAX_ITERATIONS: usize = 100;
+
+fn monotone_tangents(knots: &[(f64, f64)]) -> Vec<f64> {
+    let secants: Vec<f64> = knots
+        .windows(2)
+        .map(|w| (w[1].1 - w[0].1) / (w[1].0 - w[0].0))
+        .collect();
+
+    let n = knots.len();
+    let mut tangents = vec![0.0; n];
+    tangents[0] = secants[0];
+    tangents[n - 1] = secants[n - 2];
+    for k in 1..n - 1 {
+        if secants[k - 1] * secants[k] > 0.0 {
+            tangents[k] = 0.5 * (secants[k - 1] + secants[k]);
+        }
+    }
+
+    for (k, secant) in secants.iter().enumerate() {
+        if *secant == 0.0 {
+            tangents[k] = 0.0;
+            tangents[k + 1] = 0.0;
+            continue;
+        }
+
+        let a = tangents[k] / secant;
+        let b = tangents[k + 1] / secant;
+        let s = a * a + b * b;
+        if s > 9.0 {
+            let t = 3.0 / s.sqrt();
+            tangents[k] = t * a * secant;
+            tangents[k + 1] = t * b * secant;
+        }
+    }
+
+    tangents
+}
+
+impl<N> Scale<N> for SmoothBrokenScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let delegated_relative = self.delegate.to_relative(absolute);
+        self.smooth_y(delegated_relative)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let delegated_relative = self.smooth_x(relative);
+        self.delegate.to_absolute(delegated_relative)
+    }
+
+    fn max(&self) -> N {
+        self.delegate.max()
+    }
+
+    fn min(&self) -> N {
+        self.delegate.min()
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -123,7 +299,7 @@ mod test {
 
     #[test]
     fn test_broken_scale() {
-        let broken = BrokenScale::new(-120_f64, 12_f64, &vec![]);
+        let broken = BrokenScale::new(-120_f64, 12_f64, &[]);
 
         assert_approx_eq!(-120.0, broken.to_absolute(0.0));
         assert_approx_eq!(0.0, broken.to_relative(-120.0));
@@ -137,7 +313,7 @@ mod test {
 
     #[test]
     fn test_broken_scale_converter() {
-        let broken = BrokenScale::new(-120_f64, 12_f64, &vec![]);
+        let broken = BrokenScale::new(-120_f64, 12_f64, &[]);
         let linear = LinearScale::inverted(100_f64, 200_f64);
         let conv = (linear, broken);
 
@@ -153,7 +329,7 @@ mod test {
 
     #[test]
     fn test_broken_scale_converter_add() {
-        let broken = BrokenScale::new(-120_f64, 12_f64, &vec![]);
+        let broken = BrokenScale::new(-120_f64, 12_f64, &[]);
         let linear = LinearScale::inverted(100_f64, 200_f64);
         let conv = (linear, broken);
 
@@ -165,7 +341,7 @@ mod test {
 
     #[test]
     fn test_broken_scale_converter_add_clamped() {
-        let broken = BrokenScale::new(-120_f64, 12_f64, &vec![]);
+        let broken = BrokenScale::new(-120_f64, 12_f64, &[]);
         let linear = LinearScale::inverted(100_f64, 200_f64);
         let conv = (linear, broken);
 
@@ -177,7 +353,7 @@ mod test {
 
     #[test]
     fn test_broken_scale_converter_add_clamped_lower_bound() {
-        let broken = BrokenScale::new(-120_f64, 12_f64, &vec![]);
+        let broken = BrokenScale::new(-120_f64, 12_f64, &[]);
         let linear = LinearScale::inverted(100_f64, 200_f64);
         let conv = (linear, broken);
 
@@ -189,7 +365,7 @@ mod test {
 
     #[test]
     fn test_broken_scale_converter_add_clamped_upper_bound() {
-        let broken = BrokenScale::new(-120_f64, 12_f64, &vec![]);
+        let broken = BrokenScale::new(-120_f64, 12_f64, &[]);
         let linear = LinearScale::inverted(100_f64, 200_f64);
         let conv = (linear, broken);
 
@@ -198,4 +374,107 @@ mod test {
 
         assert_approx_eq!(12.0, d_broke);
     }
+
+    #[test]
+    fn test_smooth_broken_scale() {
+        let smooth = SmoothBrokenScale::new(-120_f64, 12_f64, &[]);
+
+        assert_approx_eq!(-120.0, smooth.to_absolute(0.0));
+        assert_approx_eq!(12.0, smooth.to_absolute(1.0));
+        assert_approx_eq!(-54.0, smooth.to_absolute(0.5));
+        assert_approx_eq!(0.5, smooth.to_relative(-54.0));
+    }
+
+    #[test]
+    fn test_smooth_broken_scale_steps() {
+        let steps = [(-60_f64, 0.25), (-20.0, 0.5), (0.0, 0.75)];
+        let smooth = SmoothBrokenScale::new(-120_f64, 12_f64, &steps);
+
+        for (abs, rel) in steps.iter() {
+            assert_approx_eq!(*rel, smooth.to_relative(*abs));
+            assert_approx_eq!(*abs, smooth.to_absolute(*rel));
+        }
+        assert_approx_eq!(0.0, smooth.to_relative(-120.0));
+        assert_approx_eq!(1.0, smooth.to_relative(12.0));
+
+        let mut previous = f64::NEG_INFINITY;
+        for i in 0..=1000 {
+            let abs = -120.0 + 132.0 * i as f64 / 1000.0;
+            let rel = smooth.to_relative(abs);
+            assert!(rel >= previous);
+            assert_approx_eq!(abs, smooth.to_absolute(rel), 1e-9);
+            previous = rel;
+        }
+    }
+
+    #[test]
+    fn test_smooth_broken_scale_no_kinks() {
+        let steps = [(-60_f64, 0.25), (-20.0, 0.5), (0.0, 0.75)];
+        let smooth = SmoothBrokenScale::new(-120_f64, 12_f64, &steps);
+
+        let h = 1e-6;
+        for (abs, _) in steps.iter() {
+            let left = (smooth.to_relative(*abs) - smooth.to_relative(abs - h)) / h;
+            let right = (smooth.to_relative(abs + h) - smooth.to_relative(*abs)) / h;
+            assert_approx_eq!(left, right, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_smooth_broken_scale_flat_step() {
+        let steps = [(-60_f64, 0.5), (-20.0, 0.5)];
+        let smooth = SmoothBrokenScale::new(-120_f64, 12_f64, &steps);
+
+        assert_approx_eq!(0.5, smooth.to_relative(-40.0));
+        assert_approx_eq!(-60.0, smooth.to_absolute(0.5));
+        assert_approx_eq!(0.75, smooth.to_relative(smooth.to_absolute(0.75)));
+    }
+
+    #[test]
+    fn test_smooth_broken_scale_out_of_range() {
+        let smooth = SmoothBrokenScale::new(0_f64, 100_f64, &[(50.0, 0.8)]);
+
+        let below = smooth.to_relative(-10.0);
+        let above = smooth.to_relative(110.0);
+        assert!(below < 0.0);
+        assert!(above > 1.0);
+        assert_approx_eq!(-10.0, smooth.to_absolute(below));
+        assert_approx_eq!(110.0, smooth.to_absolute(above));
+    }
+
+    #[test]
+    fn test_smooth_broken_scale_step_at_ends() {
+        let steps = [(0_f64, 0.0), (50.0, 0.8), (50.0, 0.9), (100.0, 1.0)];
+        let smooth = SmoothBrokenScale::new(0_f64, 100_f64, &steps);
+
+        assert_approx_eq!(0.0, smooth.to_relative(0.0));
+        assert_approx_eq!(0.8, smooth.to_relative(50.0));
+        assert_approx_eq!(1.0, smooth.to_relative(100.0));
+
+        let below = smooth.to_relative(-10.0);
+        let above = smooth.to_relative(110.0);
+        assert!(below.is_finite() && below < 0.0);
+        assert!(above.is_finite() && above > 1.0);
+        assert_approx_eq!(-10.0, smooth.to_absolute(below));
+        assert_approx_eq!(110.0, smooth.to_absolute(above));
+    }
+
+    #[test]
+    fn test_smooth_broken_scale_descending_steps() {
+        let steps = [(30_f64, 0.6), (60.0, 0.4), (80.0, 1.2)];
+        let smooth = SmoothBrokenScale::new(0_f64, 100_f64, &steps);
+
+        assert_approx_eq!(0.6, smooth.to_relative(30.0));
+        assert_approx_eq!(30.0, smooth.to_absolute(0.6));
+
+        let mut previous = f64::NEG_INFINITY;
+        for i in 0..=100 {
+            let abs = i as f64;
+            let rel = smooth.to_relative(abs);
+            assert!(rel >= previous);
+            assert_approx_eq!(abs, smooth.to_absolute(rel), 1e-9);
+            previous = rel;
+        }
+        assert_approx_eq!(45.0, smooth.to_absolute(smooth.to_relative(45.0)), 1e-9);
+    }
 }
@@ -0,0 +1,276 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+use std::marker::PhantomData;
+
+/// A scale that maps a continuous domain to discrete outputs using explicit cut points, modelled after
+/// d3's threshold scale.
+///
+/// With `n` thresholds there are `n + 1` buckets. A value equal to a threshold belongs to the bucket above it.
+/// Thresholds are compared in the relative space of the underlying scale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThresholdScale<N, S, T> {
+    scale: S,
+    thresholds: Vec<N>,
+    relative_thresholds: Vec<f64>,
+    outputs: Vec<T>,
+}
+
+impl<N, S, T> ThresholdScale<N, S, T>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    /// Create a new threshold scale. Fails if the thresholds are not ascending on the provided scale
+    /// or if there is not exactly one more output than there are thresholds.
+    pub fn new(
+        scale: S,
+        thresholds: Vec<N>,
+        outputs: Vec<T>,
+    ) -> Result<ThresholdScale<N, S, T>, ScaleError> {
+        if outputs.len() != thresholds.len() + 1 {
+            return Err(ScaleError::OutputCountMismatch {
+                expected: thresholds.len() + 1,
+                actual: outputs.len(),
+            });
+        }
+
+        let relative_thresholds: Vec<f64> = thresholds
+            .iter()
+            .map(|t| scale.to_relative(t.clone()))
+            .collect();
+        if relative_thresholds
+            .windows(2)
+            .any(|w| w[0] >= w[1] || w[0].is_nan())
+        {
+            return Err(ScaleError::UnsortedThresholds);
+        }
+
+        Ok(ThresholdScale {
+            scale,
+            thresholds,
+            relative_thresholds,
+            outputs,
+        })
+    }
+
+    pub fn thresholds(&self) -> &[N] {
+        &self.thresholds
+    }
+
+    pub fn outputs(&self) -> &[T] {
+        &self.outputs
+    }
+
+    /// The index of the bucket a value falls into.
+    pub fn bucket(&self, value: N) -> usize {
+        let relative = self.scale.to_relative(value);
+        self.relative_thresholds
+            .iter()
+            .take_while(|t| **t <= relative)
+            .count()
+    }
+
+    /// The output for a value.
+    pub fn map(&self, value: N) -> &T {
+        &self.outputs[self.bucket(value)]
+    }
+
+    /// The domain interval that produces a bucket. The first bucket starts at the minimum and the last bucket
+    /// ends at the maximum of the underlying scale. `None` if there is no such bucket.
+    pub fn extent(&self, bucket: usize) -> Option<(N, N)> {
+        if bucket >= self.outputs.len() {
+            return None;
+        }
+
+        let lower = match bucket {
+            0 => self.scale.min(),
+            _ => self.thresholds[bucket - 1].clone(),
+        };
+        let upper = match self.thresholds.get(bucket) {
+            Some(threshold) => threshold.clone(),
+            None => self.scale.max(),
+        };
+        Some((lower, upper))
+    }
+
+    /// The domain interval that produces an output, see [`extent`](ThresholdScale::extent).
+    /// `None` if the output is not part of this scale.
+    pub fn invert_extent(&self, output: &T) -> Option<(N, N)>
+    where
+        T: PartialEq,
+    {
+        let bucket = self.outputs.iter().position(|o| o == output)?;
+        self.extent(bucket)
+    }
+}
+
+/// A scale that maps a continuous domain to discrete outputs using buckets of equal width, modelled after
+/// d3's quantize scale.
+///
+/// The buckets are of equal width in the relative space of the underlying scale, so bucketing can happen
+/// in e.g. logarithmic or dB space. Values outside of the underlying scale's range are clamped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizeScale<N, S, T> {
+    scale: S,
+    outputs: Vec<T>,
+    _number: PhantomData<N>,
+}
+
+impl<N, S, T> QuantizeScale<N, S, T>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    /// Create a new quantize scale with one bucket per output. Fails if no outputs are provided.
+    pub fn new(scale: S, outputs: Vec<T>) -> Result<QuantizeScale<N, S, T>, ScaleError> {
+        if outputs.is_empty() {
+            return Err(ScaleError::NoValues);
+        }
+
+        Ok(QuantizeScale {
+            scale,
+            outputs,
+            _number: PhantomData,
+        })
+    }
+
+    pub fn outputs(&self) -> &[T] {
+        &self.outputs
+    }
+
+    /// The absolute values separating the buckets.
+    pub fn thresholds(&self) -> Vec<N> {
+        (1..self.outputs.len())
+            .map(|i| self.scale.to_absolute(self.bucket_start(i)))
+            .collect()
+    }
+
+    /// The index of the bucket a value falls into.
+    pub fn bucket(&self, value: N) -> usize {
+        let relative = self.scale.to_clamped_relative(value);
+        let bucket = (relative * self.outputs.len() as f64).floor();
+        bucket.max(0.0).min((self.outputs.len() - 1) as f64) as usize
+    }
+
+    /// The output for a value.
+    pub fn map(&self, value: N) -> &T {
+        &self.outputs[self.bucket(value)]
+    }
+
+    /// The domain interval that produces a bucket, `None` if there is no such bucket.
+    pub fn extent(&self, bucket: usize) -> Option<(N, N)> {
+        if bucket >= self.outputs.len() {
+            return None;
+        }
+
+        Some((
+            self.scale.to_absolute(self.bucket_start(bucket)),
+            self.scale.to_absolute(self.bucket_start(bucket + 1)),
+        ))
+    }
+
+    /// The domain interval that produces an output, `None` if the output is not part of this scale.
+    pub fn invert_extent(&self, output: &T) -> Option<(N, N)>
+    where
+        T: PartialEq,
+    {
+        let bucket = self.outputs.iter().position(|o| o == output)?;
+        self.extent(bucket)
+    }
+
+    fn bucket_start(&self, bucket: usize) -> f64 {
+        bucket as f64 / self.outputs.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_threshold() {
+        let scale = ThresholdScale::new(
+            LogarithmicScale::new(1_f64, 1000_f64),
+            vec![10.0, 100.0],
+            vec!["low", "mid", "high"],
+        )
+        .unwrap();
+
+        assert_eq!(scale.map(5.0), &"low");
+        assert_eq!(scale.map(10.0), &"mid");
+        assert_eq!(scale.map(99.0), &"mid");
+        assert_eq!(scale.map(500.0), &"high");
+        assert_eq!(scale.map(5000.0), &"high");
+        assert_eq!(scale.bucket(0.5), 0);
+
+        assert_eq!(scale.invert_extent(&"low"), Some((1.0, 10.0)));
+        assert_eq!(scale.invert_extent(&"mid"), Some((10.0, 100.0)));
+        assert_eq!(scale.invert_extent(&"high"), Some((100.0, 1000.0)));
+        assert_eq!(scale.invert_extent(&"none"), None);
+        assert_eq!(scale.extent(3), None);
+    }
+
+    #[test]
+    fn test_threshold_invalid() {
+        let scale: LinearScale<f64> = LinearScale::new(0.0, 1.0);
+
+        assert_eq!(
+            ThresholdScale::new(&scale, vec![0.5], vec![1, 2, 3]),
+            Err(ScaleError::OutputCountMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            ThresholdScale::new(&scale, vec![0.6, 0.5], vec![1, 2, 3]),
+            Err(ScaleError::UnsortedThresholds)
+        );
+    }
+
+    #[test]
+    fn test_quantize() {
+        let scale = QuantizeScale::new(LinearScale::new(0_f64, 100_f64), vec![0, 1, 2, 3]).unwrap();
+
+        assert_eq!(*scale.map(0.0), 0);
+        assert_eq!(*scale.map(24.9), 0);
+        assert_eq!(*scale.map(25.0), 1);
+        assert_eq!(*scale.map(99.0), 3);
+        assert_eq!(*scale.map(100.0), 3);
+        assert_eq!(*scale.map(-10.0), 0);
+        assert_eq!(*scale.map(200.0), 3);
+
+        assert_eq!(scale.thresholds(), vec![25.0, 50.0, 75.0]);
+        assert_eq!(scale.invert_extent(&2), Some((50.0, 75.0)));
+        assert_eq!(scale.extent(4), None);
+
+        let empty: Result<QuantizeScale<f64, _, u8>, _> =
+            QuantizeScale::new(LinearScale::new(0_f64, 1_f64), vec![]);
+        assert_eq!(empty, Err(ScaleError::NoValues));
+    }
+
+    #[test]
+    fn test_quantize_meter_segments() {
+        let meter = QuantizeScale::new(
+            DecibelScale::amplitude(-60.0, 0.0).unwrap(),
+            (0..6).collect(),
+        )
+        .unwrap();
+
+        assert_eq!(*meter.map(0.0), 0);
+        assert_eq!(*meter.map(0.001), 0);
+        assert_eq!(*meter.map(0.1), 4);
+        assert_eq!(*meter.map(1.0), 5);
+        assert_eq!(*meter.map(2.0), 5);
+
+        let (lower, upper): (f64, f64) = meter.extent(4).unwrap();
+        assert_approx_eq!(lower, 0.1);
+        assert_approx_eq!(upper, 10f64.powf(-0.5));
+
+        let thresholds: Vec<f64> = meter.thresholds();
+        assert_eq!(thresholds.len(), 5);
+        assert_approx_eq!(thresholds[0], 10f64.powf(-2.5));
+    }
+}
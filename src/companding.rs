@@ -0,0 +1,299 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// The value of mu used by G.711 mu-law.
+pub const G711_MU: f64 = 255.0;
+
+/// The value of A used by G.711 A-law.
+pub const G711_A: f64 = 87.6;
+
+/// A continuous mu-law companding scale for signal values between `-peak` and `peak`.
+///
+/// The relative value is the compressed signal mapped from -1.0..1.0 to 0.0..1.0, so a signal of 0.0
+/// lands at relative 0.5.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MuLawScale<N> {
+    min: N,
+    max: N,
+    peak: f64,
+    mu: f64,
+}
+
+impl<N> MuLawScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a new scale. Fails if the peak is not a finite value greater than zero or if mu is not a finite value greater than zero.
+    pub fn new(peak: N, mu: f64) -> Result<MuLawScale<N>, ScaleError> {
+        let peak_f64 = peak.clone().to_float();
+        if !peak_f64.is_finite() || peak_f64 <= 0.0 {
+            return Err(ScaleError::InvalidPeak);
+        }
+        if !mu.is_finite() || mu <= 0.0 {
+            return Err(ScaleError::InvalidCompression);
+        }
+
+        Ok(MuLawScale {
+            min: N::from_float(-peak_f64),
+            max: peak,
+            peak: peak_f64,
+            mu,
+        })
+    }
+
+    /// Create a mu-law scale using the parameter of G.711 mu-law.
+    pub fn standard(peak: N) -> Result<MuLawScale<N>, ScaleError> {
+        MuLawScale::new(peak, G711_MU)
+    }
+
+    pub fn mu(&self) -> f64 {
+        self.mu
+    }
+
+    /// Quantize a signal value to an 8-bit code, see [`encode_8bit`].
+    pub fn encode(&self, absolute: N) -> u8 {
+        encode_8bit(self, absolute)
+    }
+
+    /// Convert an 8-bit code back into a signal value, see [`decode_8bit`].
+    pub fn decode(&self, code: u8) -> N {
+        decode_8bit(self, code)
+    }
+}
+
+impl<N> Scale<N> for MuLawScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let x = absolute.to_float() / self.peak;
+        let y = x.signum() * (self.mu * x.abs()).ln_1p() / self.mu.ln_1p();
+        (y + 1.0) / 2.0
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let y = relative * 2.0 - 1.0;
+        let x = y.signum() * (y.abs() * self.mu.ln_1p()).exp_m1() / self.mu;
+        N::from_float(x * self.peak)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// A continuous A-law companding scale for signal values between `-peak` and `peak`.
+///
+/// The relative value is the compressed signal mapped from -1.0..1.0 to 0.0..1.0, so a signal of 0.0
+/// lands at relative 0.5.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ALawScale<N> {
+    min: N,
+    max: N,
+    peak: f64,
+    a: f64,
+}
+
+impl<N> ALawScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a new scale. Fails if the peak is not a finite value greater than zero or if A is not a finite value of at least 1.0.
+    pub fn new(peak: N, a: f64) -> Result<ALawScale<N>, ScaleError> {
+        let peak_f64 = peak.clone().to_float();
+        if !peak_f64.is_finite() || peak_f64 <= 0.0 {
+            return Err(ScaleError::InvalidPeak);
+        }
+        if !a.is_finite() || a < 1.0 {
+            return Err(ScaleError::InvalidCompression);
+        }
+
+        Ok(ALawScale {
+            min: N::from_float(-peak_f64),
+            max: peak,
+            peak: peak_f64,
+            a,
+        })
+    }
+
+    /// Create an A-law scale using the parameter of G.711 A-law.
+    pub fn standard(peak: N) -> Result<ALawScale<N>, ScaleError> {
+        ALawScale::new(peak, G711_A)
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Quantize a signal value to an 8-bit code, see [`encode_8bit`].
+    pub fn encode(&self, absolute: N) -> u8 {
+        encode_8bit(self, absolute)
+    }
+
+    /// Convert an 8-bit code back into a signal value, see [`decode_8bit`].
+    pub fn decode(&self, code: u8) -> N {
+        decode_8bit(self, code)
+    }
+}
+
+impl<N> Scale<N> for ALawScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let x = absolute.to_float() / self.peak;
+        let norm = 1.0 + self.a.ln();
+        let y = if x.abs() < 1.0 / self.a {
+            self.a * x.abs() / norm
+        } else {
+            (1.0 + (self.a * x.abs()).ln()) / norm
+        };
+        (x.signum() * y + 1.0) / 2.0
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let y = relative * 2.0 - 1.0;
+        let norm = 1.0 + self.a.ln();
+        let x = if y.abs() < 1.0 / norm {
+            y.abs() * norm / self.a
+        } else {
+            (y.abs() * norm - 1.0).exp() / self.a
+        };
+        N::from_float(y.signum() * x * self.peak)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// Quantize an absolute value to an 8-bit code by rounding its clamped relative value to one of 256 steps.
+///
+/// Note that the codes are plain quantized relative values, not the bit layout used by G.711 on the wire.
+pub fn encode_8bit<N, S>(scale: &S, absolute: N) -> u8
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    (scale.to_clamped_relative(absolute) * 255.0).round() as u8
+}
+
+/// Convert an 8-bit code produced by [`encode_8bit`] back into an absolute value.
+pub fn decode_8bit<N, S>(scale: &S, code: u8) -> N
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    scale.to_absolute(code as f64 / 255.0)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_mu_law() {
+        let scale: MuLawScale<f64> = MuLawScale::standard(1.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(-1.0), 0.0);
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(1.0), 1.0);
+        assert_approx_eq!(scale.to_relative(0.5), (1.0 + 0.875_703_07) / 2.0);
+        assert_approx_eq!(scale.to_relative(-0.01), (1.0 - 0.228_477_38) / 2.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), -1.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 1.0);
+
+        for i in -10..=10 {
+            let x = i as f64 / 10.0;
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(x)), x);
+        }
+    }
+
+    #[test]
+    fn test_a_law() {
+        let scale: ALawScale<f64> = ALawScale::standard(1.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(-1.0), 0.0);
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(1.0), 1.0);
+        assert_approx_eq!(scale.to_relative(0.5), (1.0 + 0.873_346_44) / 2.0);
+        assert_approx_eq!(scale.to_relative(-0.005), (1.0 - 0.080_032_44) / 2.0);
+
+        let boundary = 1.0 / 87.6;
+        assert_approx_eq!(
+            scale.to_relative(boundary - 1e-12),
+            scale.to_relative(boundary + 1e-12)
+        );
+
+        for i in -10..=10 {
+            let x = i as f64 / 10.0;
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(x)), x);
+            let x = x / 100.0;
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(x)), x);
+        }
+    }
+
+    #[test]
+    fn test_companding_parameters() {
+        let mu: MuLawScale<f64> = MuLawScale::new(32768.0, 100.0).unwrap();
+        assert_approx_eq!(mu.mu(), 100.0);
+        assert_approx_eq!(mu.min(), -32768.0);
+        assert_approx_eq!(mu.max(), 32768.0);
+        assert_approx_eq!(mu.to_absolute(mu.to_relative(1000.0)), 1000.0, 1e-9);
+
+        let a: ALawScale<f64> = ALawScale::new(32768.0, 50.0).unwrap();
+        assert_approx_eq!(a.a(), 50.0);
+        assert_approx_eq!(a.to_absolute(a.to_relative(-100.0)), -100.0, 1e-9);
+    }
+
+    #[test]
+    fn test_companding_8bit() {
+        let mu: MuLawScale<f64> = MuLawScale::standard(1.0).unwrap();
+        let a: ALawScale<f64> = ALawScale::standard(1.0).unwrap();
+
+        assert_eq!(mu.encode(-1.0), 0);
+        assert_eq!(mu.encode(0.0), 128);
+        assert_eq!(mu.encode(1.0), 255);
+        assert_eq!(mu.encode(2.0), 255);
+        assert_approx_eq!(mu.decode(0), -1.0);
+        assert_approx_eq!(mu.decode(255), 1.0);
+
+        for code in 0..=255 {
+            assert_eq!(mu.encode(mu.decode(code)), code);
+            assert_eq!(a.encode(a.decode(code)), code);
+        }
+
+        let meter: LinearScale<f64> = LinearScale::new(0.0, 255.0);
+        assert_approx_eq!((&meter, &mu).convert(255.0), 1.0);
+        assert_approx_eq!((&meter, &mu).convert_back(mu.decode(200)), 200.0);
+    }
+
+    #[test]
+    fn test_companding_invalid_parameters() {
+        let mu: Result<MuLawScale<f64>, _> = MuLawScale::new(1.0, 0.0);
+        assert_eq!(mu, Err(ScaleError::InvalidCompression));
+        let mu: Result<MuLawScale<f64>, _> = MuLawScale::new(0.0, 255.0);
+        assert_eq!(mu, Err(ScaleError::InvalidPeak));
+        let mu: Result<MuLawScale<f64>, _> = MuLawScale::standard(-1.0);
+        assert_eq!(mu, Err(ScaleError::InvalidPeak));
+
+        let a: Result<ALawScale<f64>, _> = ALawScale::new(1.0, 0.5);
+        assert_eq!(a, Err(ScaleError::InvalidCompression));
+        let a: Result<ALawScale<f64>, _> = ALawScale::new(f64::INFINITY, 87.6);
+        assert_eq!(a, Err(ScaleError::InvalidPeak));
+        assert!(ALawScale::<f64>::new(1.0, 1.0).is_ok());
+    }
+}
@@ -13,14 +13,12 @@ where
 
     fn add_external(&self, external_delta: E, internal_value: I) -> I {
         let external_value = self.convert_back(internal_value);
-        let new_internal_value = self.convert(external_value + external_delta);
-        new_internal_value
+        self.convert(external_value + external_delta)
     }
 
     fn add_internal(&self, internal_delta: I, external_value: E) -> E {
         let internal_value = self.convert(external_value);
-        let new_external_value = self.convert_back(internal_value + internal_delta);
-        new_external_value
+        self.convert_back(internal_value + internal_delta)
     }
 }
 
@@ -83,6 +81,25 @@ where
         let rel = internal.to_relative(internal_value);
         external.to_absolute(rel)
     }
+
+    // Deltas are applied in relative space via the scales' delta functions, so scales that wrap around
+    // (see CyclicScale) can take the shortest path across their seam instead of jumping.
+
+    fn add_external(&self, external_delta: E, internal_value: I) -> I {
+        let external = &self.0;
+        let internal = &self.1;
+        let rel = internal.to_relative(internal_value);
+        let rel_delta = external.to_relative_delta(external_delta, rel);
+        internal.to_absolute(rel + rel_delta)
+    }
+
+    fn add_internal(&self, internal_delta: I, external_value: E) -> E {
+        let external = &self.0;
+        let internal = &self.1;
+        let rel = external.to_relative(external_value);
+        let rel_delta = internal.to_relative_delta(internal_delta, rel);
+        external.to_absolute(rel + rel_delta)
+    }
 }
 
 impl<E, I, SE, SI> ClampingConverter<E, I> for (SE, SI)
@@ -0,0 +1,281 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+const INVERSION_TOLERANCE: f64 = 1e-12;
+const INVERSION_MAX_ITERATIONS: usize = 100;
+
+/// An invertible, monotonically increasing mapping of relative values, mapping 0.0 to 0.0 and 1.0 to 1.0.
+///
+/// Curves can be applied on top of any scale using [`Curved`], e.g. to give a logarithmic frequency knob
+/// an extra ease-in.
+pub trait RelativeCurve {
+    fn apply(&self, relative: f64) -> f64;
+    fn invert(&self, relative: f64) -> f64;
+}
+
+impl<C> RelativeCurve for &C
+where
+    C: RelativeCurve + ?Sized,
+{
+    fn apply(&self, relative: f64) -> f64 {
+        C::apply(self, relative)
+    }
+
+    fn invert(&self, relative: f64) -> f64 {
+        C::invert(self, relative)
+    }
+}
+
+impl<C> RelativeCurve for Box<C>
+where
+    C: RelativeCurve + ?Sized,
+{
+    fn apply(&self, relative: f64) -> f64 {
+        C::apply(self, relative)
+    }
+
+    fn invert(&self, relative: f64) -> f64 {
+        C::invert(self, relative)
+    }
+}
+
+/// A library of common easing shapes with closed form inverses. All shapes are monotonic on the whole real line,
+/// so relative values outside of 0.0 to 1.0 remain invertible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// `x²`, mirrored for negative values.
+    Quadratic,
+    /// `x³`.
+    Cubic,
+    /// `(e^(k·x) - 1) / (e^k - 1)`. Positive values of `k` ease in, negative values ease out and 0.0 is linear.
+    Exponential(f64),
+    /// A cubic ease-in-out: slow at both ends and fast in the middle.
+    EaseInOut,
+}
+
+impl RelativeCurve for Easing {
+    fn apply(&self, relative: f64) -> f64 {
+        match *self {
+            Easing::Quadratic => relative * relative.abs(),
+            Easing::Cubic => relative.powi(3),
+            Easing::Exponential(0.0) => relative,
+            Easing::Exponential(k) => (k * relative).exp_m1() / k.exp_m1(),
+            Easing::EaseInOut => {
+                if relative < 0.5 {
+                    4.0 * relative.powi(3)
+                } else {
+                    1.0 - 0.5 * (2.0 - 2.0 * relative).powi(3)
+                }
+            }
+        }
+    }
+
+    fn invert(&self, relative: f64) -> f64 {
+        match *self {
+            Easing::Quadratic => relative.signum() * relative.abs().sqrt(),
+            Easing::Cubic => relative.cbrt(),
+            Easing::Exponential(0.0) => relative,
+            Easing::Exponential(k) => (relative * k.exp_m1()).ln_1p() / k,
+            Easing::EaseInOut => {
+                if relative < 0.5 {
+                    (0.25 * relative).cbrt()
+                } else {
+                    1.0 - 0.5 * (2.0 - 2.0 * relative).cbrt()
+                }
+            }
+        }
+    }
+}
+
+/// A curve defined by an arbitrary continuous and monotonically increasing closure, inverted numerically.
+///
+/// Relative values below `f(0.0)` or above `f(1.0)` cannot be inverted and map to 0.0 and 1.0 respectively.
+/// If the inversion does not converge, [`invert`](RelativeCurve::invert) returns NaN and
+/// [`try_invert`](CustomCurve::try_invert) returns an error.
+#[derive(Debug, Clone)]
+pub struct CustomCurve<F>
+where
+    F: Fn(f64) -> f64,
+{
+    curve: F,
+}
+
+impl<F> CustomCurve<F>
+where
+    F: Fn(f64) -> f64,
+{
+    pub fn new(curve: F) -> CustomCurve<F> {
+        CustomCurve { curve }
+    }
+
+    /// Invert the curve, reporting an error if the numeric inversion does not converge, e.g. because the closure
+    /// is not continuous. [`invert`](RelativeCurve::invert) returns NaN in that case.
+    pub fn try_invert(&self, relative: f64) -> Result<f64, ScaleError> {
+        if relative <= (self.curve)(0.0) {
+            return Ok(0.0);
+        }
+        if relative >= (self.curve)(1.0) {
+            return Ok(1.0);
+        }
+
+        numeric::invert_monotonic(
+            &self.curve,
+            relative,
+            0.0,
+            1.0,
+            INVERSION_TOLERANCE,
+            INVERSION_MAX_ITERATIONS,
+        )
+        .ok_or(ScaleError::NotConverged)
+    }
+}
+
+impl<F> RelativeCurve for CustomCurve<F>
+where
+    F: Fn(f64) -> f64,
+{
+    fn apply(&self, relative: f64) -> f64 {
+        (self.curve)(relative)
+    }
+
+    fn invert(&self, relative: f64) -> f64 {
+        self.try_invert(relative).unwrap_or(f64::NAN)
+    }
+}
+
+/// An adapter that applies a [`RelativeCurve`] to the relative values of another scale.
+///
+/// Absolute values, including [`min`](Scale::min) and [`max`](Scale::max), are the same as those of the
+/// wrapped scale. Any scale can be curved using [`ScaleExt::curved`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curved<S, C> {
+    inner: S,
+    curve: C,
+}
+
+impl<S, C> Curved<S, C>
+where
+    C: RelativeCurve,
+{
+    pub fn new(inner: S, curve: C) -> Curved<S, C> {
+        Curved { inner, curve }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<N, S, C> Scale<N> for Curved<S, C>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+    C: RelativeCurve,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.curve.apply(self.inner.to_relative(absolute))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.inner.to_absolute(self.curve.invert(relative))
+    }
+
+    fn max(&self) -> N {
+        self.inner.max()
+    }
+
+    fn min(&self) -> N {
+        self.inner.min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_easing_round_trip() {
+        let curves = [
+            Easing::Quadratic,
+            Easing::Cubic,
+            Easing::Exponential(4.0),
+            Easing::Exponential(-4.0),
+            Easing::Exponential(0.0),
+            Easing::EaseInOut,
+        ];
+
+        for curve in curves.iter() {
+            assert_approx_eq!(curve.apply(0.0), 0.0);
+            assert_approx_eq!(curve.apply(1.0), 1.0);
+            for i in -10..=20 {
+                let x = i as f64 / 10.0;
+                assert_approx_eq!(curve.invert(curve.apply(x)), x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_easing_values() {
+        assert_approx_eq!(Easing::Quadratic.apply(0.5), 0.25);
+        assert_approx_eq!(Easing::Quadratic.apply(-0.5), -0.25);
+        assert_approx_eq!(Easing::Cubic.apply(0.5), 0.125);
+        assert_approx_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_approx_eq!(Easing::EaseInOut.apply(0.25), 0.0625);
+        assert_approx_eq!(Easing::EaseInOut.apply(0.75), 0.9375);
+        assert!(Easing::Exponential(4.0).apply(0.5) < 0.5);
+        assert!(Easing::Exponential(-4.0).apply(0.5) > 0.5);
+    }
+
+    #[test]
+    fn test_custom_curve() {
+        let curve = CustomCurve::new(|x: f64| x.powf(1.5));
+
+        assert_approx_eq!(curve.apply(0.25), 0.125);
+        assert_approx_eq!(curve.invert(0.125), 0.25);
+        assert_eq!(curve.invert(-1.0), 0.0);
+        assert_eq!(curve.invert(2.0), 1.0);
+        assert_approx_eq!(curve.try_invert(0.125).unwrap(), 0.25);
+    }
+
+    #[test]
+    fn test_custom_curve_not_converged() {
+        let step = CustomCurve::new(|x: f64| if x < 0.5 { 0.0 } else { 1.0 });
+
+        assert_eq!(step.try_invert(0.5), Err(ScaleError::NotConverged));
+        assert!(step.invert(0.5).is_nan());
+    }
+
+    #[test]
+    fn test_curved() {
+        let scale = LogarithmicScale::new(20_f64, 20_000_f64).curved(Easing::Quadratic);
+
+        assert_approx_eq!(scale.to_relative(20.0), 0.0);
+        assert_approx_eq!(scale.to_relative(20_000.0), 1.0);
+        assert_approx_eq!(scale.to_relative(200.0), 1.0 / 9.0);
+        assert_approx_eq!(scale.to_absolute(1.0 / 9.0), 200.0);
+        assert_eq!(scale.min(), 20.0);
+        assert_eq!(scale.max(), 20_000.0);
+
+        let knob: LinearScale<f64> = LinearScale::new(0.0, 270.0);
+        assert_approx_eq!((&knob, &scale).convert_back(200.0), 30.0);
+    }
+
+    #[test]
+    fn test_curved_custom() {
+        let scale = LinearScale::new(0_f64, 10_f64).curved(CustomCurve::new(|x: f64| x.sqrt()));
+
+        assert_approx_eq!(scale.to_relative(2.5), 0.5);
+        assert_approx_eq!(scale.to_absolute(0.5), 2.5);
+    }
+}
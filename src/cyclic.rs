@@ -0,0 +1,164 @@
+use super::convert::*;
+use super::*;
+
+/// A scale for cyclic quantities like angles, hues or times of day, where the maximum coincides with the minimum.
+///
+/// Absolute and relative values wrap around: [`to_relative`](Scale::to_relative) always returns a value
+/// in `0.0..1.0` and [`to_absolute`](Scale::to_absolute) always returns a value in `min..max`. Consequently
+/// `max()` maps to relative 0.0, just like `min()`, and clamping behaves like wrapping.
+/// Deltas take the shortest path around the cycle, so they are always within half a period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CyclicScale<N> {
+    min: N,
+    max: N,
+    min_f64: f64,
+    period: f64,
+}
+
+impl<N> CyclicScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N) -> CyclicScale<N> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+
+        CyclicScale {
+            min,
+            max,
+            min_f64,
+            period: max_f64 - min_f64,
+        }
+    }
+
+    /// The length of one cycle, i.e. `max - min`.
+    pub fn period(&self) -> N {
+        N::from_float(self.period)
+    }
+
+    /// Wrap an absolute value into `min..max`.
+    pub fn wrap(&self, absolute: N) -> N {
+        self.to_absolute(self.to_relative(absolute))
+    }
+
+    /// The signed distance from one absolute value to another along the shortest path around the cycle.
+    pub fn shortest_delta(&self, from: N, to: N) -> N {
+        let delta = to.to_float() - from.to_float();
+        N::from_float(wrap_delta(delta / self.period) * self.period)
+    }
+}
+
+impl<N> Scale<N> for CyclicScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        ((absolute.to_float() - self.min_f64) / self.period).rem_euclid(1.0)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(self.min_f64 + relative.rem_euclid(1.0) * self.period)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+
+    fn to_clamped_relative(&self, absolute: N) -> f64 {
+        self.to_relative(absolute)
+    }
+
+    fn to_clamped_absolute(&self, relative: f64) -> N {
+        self.to_absolute(relative)
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, _relative_pos: f64) -> f64 {
+        wrap_delta(absolute_delta.to_float() / self.period)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, _absolute_pos: N) -> N {
+        N::from_float(wrap_delta(relative_delta) * self.period)
+    }
+}
+
+/// Wrap a relative delta into `-0.5..0.5`.
+fn wrap_delta(relative_delta: f64) -> f64 {
+    (relative_delta + 0.5).rem_euclid(1.0) - 0.5
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_cyclic() {
+        let hue: CyclicScale<f64> = CyclicScale::new(0.0, 360.0);
+
+        assert_approx_eq!(hue.to_relative(0.0), 0.0);
+        assert_approx_eq!(hue.to_relative(90.0), 0.25);
+        assert_approx_eq!(hue.to_relative(360.0), 0.0);
+        assert_approx_eq!(hue.to_relative(370.0), 10.0 / 360.0);
+        assert_approx_eq!(hue.to_relative(-10.0), 350.0 / 360.0);
+
+        assert_approx_eq!(hue.to_absolute(0.5), 180.0);
+        assert_approx_eq!(hue.to_absolute(1.25), 90.0);
+        assert_approx_eq!(hue.to_absolute(-0.25), 270.0);
+
+        assert_approx_eq!(hue.to_clamped_relative(-10.0), 350.0 / 360.0);
+        assert_approx_eq!(hue.to_clamped_absolute(1.25), 90.0);
+
+        assert_approx_eq!(hue.wrap(725.0), 5.0);
+        assert_approx_eq!(hue.period(), 360.0);
+    }
+
+    #[test]
+    fn test_cyclic_deltas() {
+        let hue: CyclicScale<f64> = CyclicScale::new(0.0, 360.0);
+
+        assert_approx_eq!(hue.to_relative_delta(20.0, 0.95), 20.0 / 360.0);
+        assert_approx_eq!(hue.to_relative_delta(350.0, 0.5), -10.0 / 360.0);
+        assert_approx_eq!(hue.to_absolute_delta(0.1, 350.0), 36.0);
+        assert_approx_eq!(hue.to_absolute_delta(0.9, 10.0), -36.0);
+
+        assert_approx_eq!(hue.shortest_delta(350.0, 10.0), 20.0);
+        assert_approx_eq!(hue.shortest_delta(10.0, 350.0), -20.0);
+        assert_approx_eq!(hue.shortest_delta(0.0, 720.0), 0.0);
+    }
+
+    #[test]
+    fn test_cyclic_time_of_day() {
+        let clock: CyclicScale<i32> = CyclicScale::new(0, 1440);
+
+        assert_eq!(clock.to_absolute(0.5), 720);
+        assert_eq!(clock.wrap(1500), 60);
+        assert_eq!(clock.shortest_delta(1380, 60), 120);
+        assert_eq!(clock.shortest_delta(60, 1380), -120);
+    }
+
+    #[test]
+    fn test_cyclic_converter() {
+        let drag: LinearScale<f64> = LinearScale::new(0.0, 360.0);
+        let hue: CyclicScale<f64> = CyclicScale::new(0.0, 360.0);
+        let conv = (&drag, &hue);
+
+        assert_approx_eq!(conv.add_external(30.0, 350.0), 20.0);
+        assert_approx_eq!(conv.add_external(-30.0, 10.0), 340.0);
+        assert_approx_eq!(conv.add_external_clamped(30.0, 350.0), 20.0);
+
+        let encoder: CyclicScale<f64> = CyclicScale::new(0.0, 24.0);
+        let parameter: LinearScale<f64> = LinearScale::new(0.0, 240.0);
+        let conv = (&encoder, &parameter);
+
+        // crossing the seam of the encoder must not make the parameter jump
+        assert_approx_eq!(conv.add_external(2.0, 230.0), 250.0);
+        assert_approx_eq!(conv.add_external_clamped(2.0, 230.0), 240.0);
+        assert_approx_eq!(conv.add_external(-2.0, 10.0), -10.0);
+        assert_approx_eq!(conv.add_internal(20.0, 23.0), 1.0);
+    }
+}
@@ -0,0 +1,222 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// The unit the absolute values of a [`DecibelScale`] are expressed in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecibelUnit {
+    /// Absolute values are levels in dB.
+    Decibels,
+    /// Absolute values are linear amplitude gains (e.g. voltage or sample values), 1.0 being 0 dB.
+    Amplitude,
+    /// Absolute values are linear power gains, 1.0 being 0 dB.
+    Power,
+}
+
+impl DecibelUnit {
+    /// Convert a level in dB into a value of this unit.
+    pub fn from_db(self, db: f64) -> f64 {
+        match self {
+            DecibelUnit::Decibels => db,
+            DecibelUnit::Amplitude => 10f64.powf(db / 20.0),
+            DecibelUnit::Power => 10f64.powf(db / 10.0),
+        }
+    }
+
+    /// Convert a value of this unit into a level in dB.
+    pub fn to_db(self, value: f64) -> f64 {
+        match self {
+            DecibelUnit::Decibels => value,
+            DecibelUnit::Amplitude => 20.0 * value.log10(),
+            DecibelUnit::Power => 10.0 * value.log10(),
+        }
+    }
+}
+
+/// A scale that is linear in dB between a floor and a maximum level.
+///
+/// Relative 0.0 is true silence, i.e. -inf dB or a gain of 0.0, and every level at or below the floor
+/// maps to relative 0.0. Above the floor the relative value grows linearly with the level in dB.
+/// Depending on its [`DecibelUnit`] the absolute values are either levels in dB or linear gains.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecibelScale<N> {
+    min: N,
+    max: N,
+    unit: DecibelUnit,
+    floor_db: f64,
+    max_db: f64,
+    full_range: f64,
+}
+
+impl<N> DecibelScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a scale whose absolute values are levels in dB.
+    pub fn new(floor_db: f64, max_db: f64) -> Result<DecibelScale<N>, ScaleError> {
+        DecibelScale::with_unit(floor_db, max_db, DecibelUnit::Decibels)
+    }
+
+    /// Create a scale whose absolute values are linear amplitude gains.
+    pub fn amplitude(floor_db: f64, max_db: f64) -> Result<DecibelScale<N>, ScaleError> {
+        DecibelScale::with_unit(floor_db, max_db, DecibelUnit::Amplitude)
+    }
+
+    /// Create a scale whose absolute values are linear power gains.
+    pub fn power(floor_db: f64, max_db: f64) -> Result<DecibelScale<N>, ScaleError> {
+        DecibelScale::with_unit(floor_db, max_db, DecibelUnit::Power)
+    }
+
+    /// Create a scale whose absolute values are expressed in the provided unit.
+    /// Fails if the floor or the maximum level is not finite or the floor is not below the maximum level.
+    pub fn with_unit(
+        floor_db: f64,
+        max_db: f64,
+        unit: DecibelUnit,
+    ) -> Result<DecibelScale<N>, ScaleError> {
+        if !floor_db.is_finite() || !max_db.is_finite() || floor_db >= max_db {
+            return Err(ScaleError::InvalidRange);
+        }
+
+        Ok(DecibelScale {
+            min: N::from_float(unit.from_db(f64::NEG_INFINITY)),
+            max: N::from_float(unit.from_db(max_db)),
+            unit,
+            floor_db,
+            max_db,
+            full_range: max_db - floor_db,
+        })
+    }
+
+    pub fn unit(&self) -> DecibelUnit {
+        self.unit
+    }
+
+    pub fn floor_db(&self) -> f64 {
+        self.floor_db
+    }
+
+    pub fn max_db(&self) -> f64 {
+        self.max_db
+    }
+
+    /// Convert an absolute value of this scale into a level in dB.
+    pub fn to_db(&self, absolute: N) -> f64 {
+        self.unit.to_db(absolute.to_float())
+    }
+
+    /// Convert a level in dB into an absolute value of this scale.
+    pub fn from_db(&self, db: f64) -> N {
+        N::from_float(self.unit.from_db(db))
+    }
+}
+
+impl<N> Scale<N> for DecibelScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let db = self.to_db(absolute);
+        if db <= self.floor_db {
+            0.0
+        } else {
+            (db - self.floor_db) / self.full_range
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        if relative <= 0.0 {
+            self.min()
+        } else {
+            self.from_db(self.floor_db + relative * self.full_range)
+        }
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_decibel() {
+        let scale: DecibelScale<f64> = DecibelScale::new(-60.0, 12.0).unwrap();
+
+        assert_eq!(scale.to_absolute(0.0), f64::NEG_INFINITY);
+        assert_approx_eq!(scale.to_absolute(0.5), -24.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 12.0);
+
+        assert_eq!(scale.to_relative(f64::NEG_INFINITY), 0.0);
+        assert_eq!(scale.to_relative(-60.0), 0.0);
+        assert_eq!(scale.to_relative(-90.0), 0.0);
+        assert_approx_eq!(scale.to_relative(0.0), 60.0 / 72.0);
+        assert_approx_eq!(scale.to_relative(12.0), 1.0);
+
+        assert_eq!(scale.min(), f64::NEG_INFINITY);
+        assert_approx_eq!(scale.max(), 12.0);
+    }
+
+    #[test]
+    fn test_decibel_amplitude() {
+        let scale: DecibelScale<f64> = DecibelScale::amplitude(-60.0, 12.0).unwrap();
+
+        assert_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(60.0 / 72.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 3.9810717);
+
+        assert_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.to_relative(0.5), 53.9794 / 72.0);
+        assert_approx_eq!(scale.to_relative(1.0), 60.0 / 72.0);
+
+        assert_eq!(scale.min(), 0.0);
+        assert_approx_eq!(scale.max(), 3.9810717);
+    }
+
+    #[test]
+    fn test_decibel_power() {
+        let scale: DecibelScale<f64> = DecibelScale::power(-60.0, 20.0).unwrap();
+
+        assert_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.75), 1.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 100.0);
+        assert_approx_eq!(scale.to_relative(0.1), 0.625);
+    }
+
+    #[test]
+    fn test_decibel_silence_round_trip() {
+        let scale: DecibelScale<f64> = DecibelScale::amplitude(-96.0, 6.0).unwrap();
+
+        let silence = scale.to_absolute(0.0);
+        assert_eq!(scale.to_relative(silence), 0.0);
+        assert_eq!(scale.to_clamped_relative(silence), 0.0);
+        assert_eq!(scale.to_absolute(scale.to_relative(silence)), silence);
+
+        let fader: LinearScale<f64> = LinearScale::new(0.0, 100.0);
+        let conv = (fader, scale);
+        assert_eq!(conv.convert(0.0), 0.0);
+        assert_approx_eq!(conv.convert_back(0.0), 0.0);
+        assert_approx_eq!(conv.convert_back(1.0), 100.0 * 96.0 / 102.0);
+    }
+
+    #[test]
+    fn test_decibel_invalid_range() {
+        let reversed: Result<DecibelScale<f64>, _> = DecibelScale::new(12.0, -60.0);
+        assert_eq!(reversed, Err(ScaleError::InvalidRange));
+
+        let empty: Result<DecibelScale<f64>, _> = DecibelScale::amplitude(0.0, 0.0);
+        assert_eq!(empty, Err(ScaleError::InvalidRange));
+
+        let infinite: Result<DecibelScale<f64>, _> =
+            DecibelScale::with_unit(f64::NEG_INFINITY, 0.0, DecibelUnit::Power);
+        assert_eq!(infinite, Err(ScaleError::InvalidRange));
+    }
+}
@@ -0,0 +1,209 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+use std::marker::PhantomData;
+
+const CONTINUITY_TOLERANCE: f64 = 1e-9;
+
+/// The side of a [`DivergingScale`] a value lies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Centre,
+    Right,
+}
+
+/// A scale composed of two halves that meet at a centre value, which always lands exactly at relative 0.5.
+///
+/// The left half covers the relative range 0.0 to 0.5, the right half covers 0.5 to 1.0. The centre is the maximum
+/// of the left half, which must match the minimum of the right half. Both halves may be arbitrary scales,
+/// so an asymmetric range like -60..+12 dB can still have 0 dB in the middle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DivergingScale<N, L, R> {
+    left: L,
+    right: R,
+    _number: PhantomData<N>,
+}
+
+impl<N, L, R> DivergingScale<N, L, R>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    L: Scale<N>,
+    R: Scale<N>,
+{
+    /// Create a diverging scale from two halves. Fails if the maximum of the left half does not match
+    /// the minimum of the right half.
+    pub fn new(left: L, right: R) -> Result<DivergingScale<N, L, R>, ScaleError> {
+        let left_max = left.max().to_float();
+        let right_min = right.min().to_float();
+        let tolerance = CONTINUITY_TOLERANCE * left_max.abs().max(right_min.abs()).max(1.0);
+        if (left_max - right_min).abs() > tolerance {
+            return Err(ScaleError::Discontinuity { index: 0 });
+        }
+
+        Ok(DivergingScale {
+            left,
+            right,
+            _number: PhantomData,
+        })
+    }
+
+    pub fn left(&self) -> &L {
+        &self.left
+    }
+
+    pub fn right(&self) -> &R {
+        &self.right
+    }
+
+    pub fn centre(&self) -> N {
+        self.left.max()
+    }
+
+    /// Which side of the centre an absolute value lies on.
+    pub fn side(&self, absolute: N) -> Side {
+        if self.left.to_relative(absolute.clone()) < 1.0 {
+            Side::Left
+        } else if self.right.to_relative(absolute) > 0.0 {
+            Side::Right
+        } else {
+            Side::Centre
+        }
+    }
+}
+
+impl<N> DivergingScale<N, LinearScale<N>, LinearScale<N>>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a diverging scale from two linear halves. Fails if the centre does not lie strictly
+    /// between minimum and maximum.
+    pub fn linear(
+        min: N,
+        centre: N,
+        max: N,
+    ) -> Result<DivergingScale<N, LinearScale<N>, LinearScale<N>>, ScaleError> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+        let centre_f64 = centre.clone().to_float();
+        let (lower, upper) = if min_f64 < max_f64 {
+            (min_f64, max_f64)
+        } else {
+            (max_f64, min_f64)
+        };
+        if centre_f64 <= lower || centre_f64 >= upper {
+            return Err(ScaleError::CentreOutOfRange);
+        }
+
+        DivergingScale::new(
+            LinearScale::new(min, centre.clone()),
+            LinearScale::new(centre, max),
+        )
+    }
+}
+
+impl<N, L, R> Scale<N> for DivergingScale<N, L, R>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    L: Scale<N>,
+    R: Scale<N>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        match self.side(absolute.clone()) {
+            Side::Left => 0.5 * self.left.to_relative(absolute),
+            Side::Centre => 0.5,
+            Side::Right => 0.5 + 0.5 * self.right.to_relative(absolute),
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        if relative < 0.5 {
+            self.left.to_absolute(2.0 * relative)
+        } else {
+            self.right.to_absolute(2.0 * relative - 1.0)
+        }
+    }
+
+    fn max(&self) -> N {
+        self.right.max()
+    }
+
+    fn min(&self) -> N {
+        self.left.min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_diverging_linear() {
+        let scale: DivergingScale<f64, _, _> = DivergingScale::linear(-60.0, 0.0, 12.0).unwrap();
+
+        assert_eq!(scale.to_relative(0.0), 0.5);
+        assert_eq!(scale.to_absolute(0.5), 0.0);
+        assert_approx_eq!(scale.to_relative(-60.0), 0.0);
+        assert_approx_eq!(scale.to_relative(-30.0), 0.25);
+        assert_approx_eq!(scale.to_relative(6.0), 0.75);
+        assert_approx_eq!(scale.to_relative(12.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.25), -30.0);
+        assert_approx_eq!(scale.to_absolute(0.75), 6.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 12.0);
+
+        assert_eq!(scale.min(), -60.0);
+        assert_eq!(scale.max(), 12.0);
+        assert_eq!(scale.centre(), 0.0);
+    }
+
+    #[test]
+    fn test_diverging_side() {
+        let scale: DivergingScale<f64, _, _> = DivergingScale::linear(-1.0, 0.0, 1.0).unwrap();
+
+        assert_eq!(scale.side(-0.5), Side::Left);
+        assert_eq!(scale.side(0.0), Side::Centre);
+        assert_eq!(scale.side(0.5), Side::Right);
+        assert_eq!(scale.side(-2.0), Side::Left);
+        assert_eq!(scale.side(2.0), Side::Right);
+    }
+
+    #[test]
+    fn test_diverging_mixed_halves() {
+        let scale = DivergingScale::new(
+            LinearScale::new(-1_f64, 0_f64),
+            LogarithmicScale::with_offset(0_f64, 1000_f64, 1.0),
+        )
+        .unwrap();
+
+        assert_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(1000.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(scale.to_relative(-0.3)), -0.3);
+        assert_approx_eq!(scale.to_absolute(scale.to_relative(100.0)), 100.0);
+    }
+
+    #[test]
+    fn test_diverging_invalid_centre() {
+        let err = DivergingScale::linear(-1.0, 1.0, 1.0);
+        assert_eq!(err, Err(ScaleError::CentreOutOfRange));
+
+        assert!(DivergingScale::linear(1.0, 0.0, -1.0).is_ok());
+    }
+
+    #[test]
+    fn test_diverging_discontinuity() {
+        let gap = DivergingScale::new(
+            LinearScale::new(-60_f64, 0_f64),
+            LinearScale::new(6_f64, 12_f64),
+        );
+        assert_eq!(gap.err(), Some(ScaleError::Discontinuity { index: 0 }));
+
+        let nearly = DivergingScale::new(
+            LinearScale::new(-60_f64, 0.1 + 0.2),
+            LinearScale::new(0.3_f64, 12_f64),
+        );
+        assert!(nearly.is_ok());
+    }
+}
@@ -0,0 +1,219 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A scale for choice parameters that maps a list of values of an arbitrary type to relative values.
+///
+/// Since `T` is not numeric, this does not implement [`Scale`], but it follows the same relative
+/// conventions as a [`SteppedScale`](crate::prelude::SteppedScale) with `len() - 1` steps:
+/// the value at index `i` has the relative value `i / (len() - 1)`, and the relative range is split into
+/// `len()` bands of equal width, the value at index `i` being selected by relative values between
+/// `i / len()` and `(i + 1) / len()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumScale<T> {
+    values: Vec<T>,
+    labels: Option<Vec<String>>,
+}
+
+impl<T> EnumScale<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Create an enum scale of the provided values. Fails if no values are provided.
+    pub fn new(values: Vec<T>) -> Result<EnumScale<T>, ScaleError> {
+        if values.is_empty() {
+            return Err(ScaleError::NoValues);
+        }
+
+        Ok(EnumScale {
+            values,
+            labels: None,
+        })
+    }
+
+    /// Create an enum scale of the provided values and their labels. Fails if no values are provided.
+    pub fn labelled(entries: Vec<(T, &str)>) -> Result<EnumScale<T>, ScaleError> {
+        let labels = entries.iter().map(|(_, l)| l.to_string()).collect();
+        let values = entries.into_iter().map(|(v, _)| v).collect();
+        let mut scale = EnumScale::new(values)?;
+        scale.labels = Some(labels);
+        Ok(scale)
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The number of values. This is always at least 1.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Always false, since an enum scale can't be created without values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The number of steps between the values, i.e. `len() - 1`.
+    pub fn step_count(&self) -> usize {
+        self.values.len() - 1
+    }
+
+    pub fn index_of(&self, value: &T) -> Option<usize> {
+        self.values.iter().position(|v| v == value)
+    }
+
+    /// The index of the value a relative value selects. Out of range relative values select the first or last value.
+    pub fn index_of_relative(&self, relative: f64) -> usize {
+        let index = (relative * self.len() as f64).floor();
+        index.max(0.0).min(self.step_count() as f64) as usize
+    }
+
+    /// The relative value of the value at an index.
+    pub fn relative_of_index(&self, index: usize) -> f64 {
+        if self.step_count() == 0 {
+            0.0
+        } else {
+            index.min(self.step_count()) as f64 / self.step_count() as f64
+        }
+    }
+
+    /// Convert a value into its relative value, `None` if the value is not part of this scale.
+    pub fn to_relative(&self, value: &T) -> Option<f64> {
+        self.index_of(value).map(|i| self.relative_of_index(i))
+    }
+
+    /// Convert a relative value into the value it selects.
+    pub fn to_absolute(&self, relative: f64) -> &T {
+        &self.values[self.index_of_relative(relative)]
+    }
+
+    /// The label of a value, `None` if the scale has no labels or the value is not part of this scale.
+    pub fn label(&self, value: &T) -> Option<&str> {
+        self.index_of(value).and_then(|i| self.label_at(i))
+    }
+
+    /// The label of the value at an index, `None` if the scale has no labels or the index is out of range.
+    pub fn label_at(&self, index: usize) -> Option<&str> {
+        self.labels
+            .as_ref()
+            .and_then(|labels| labels.get(index))
+            .map(String::as_str)
+    }
+
+    /// The value with the provided label, `None` if no value has that label.
+    pub fn value_of_label(&self, label: &str) -> Option<&T> {
+        let labels = self.labels.as_ref()?;
+        let index = labels.iter().position(|l| l == label)?;
+        self.values.get(index)
+    }
+
+    /// Select a value via the relative value of a value of another scale, e.g. a host's normalized parameter value.
+    pub fn convert_from<E, SE>(&self, external: &SE, external_value: E) -> &T
+    where
+        E: Sub<Output = E> + Add<Output = E> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        SE: Scale<E>,
+    {
+        self.to_absolute(external.to_relative(external_value))
+    }
+
+    /// Convert a value into the value of another scale at the same relative position,
+    /// `None` if the value is not part of this scale.
+    pub fn convert_to<E, SE>(&self, external: &SE, value: &T) -> Option<E>
+    where
+        E: Sub<Output = E> + Add<Output = E> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        SE: Scale<E>,
+    {
+        self.to_relative(value).map(|r| external.to_absolute(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Waveform {
+        Sine,
+        Triangle,
+        Saw,
+        Square,
+    }
+
+    #[test]
+    fn test_enum() {
+        let scale = EnumScale::new(vec![
+            Waveform::Sine,
+            Waveform::Triangle,
+            Waveform::Saw,
+            Waveform::Square,
+        ])
+        .unwrap();
+
+        assert_eq!(scale.len(), 4);
+        assert_eq!(scale.step_count(), 3);
+
+        assert_approx_eq!(scale.to_relative(&Waveform::Sine).unwrap(), 0.0);
+        assert_approx_eq!(scale.to_relative(&Waveform::Triangle).unwrap(), 1.0 / 3.0);
+        assert_approx_eq!(scale.to_relative(&Waveform::Square).unwrap(), 1.0);
+
+        assert_eq!(scale.to_absolute(0.0), &Waveform::Sine);
+        assert_eq!(scale.to_absolute(0.24), &Waveform::Sine);
+        assert_eq!(scale.to_absolute(0.26), &Waveform::Triangle);
+        assert_eq!(scale.to_absolute(0.74), &Waveform::Saw);
+        assert_eq!(scale.to_absolute(1.0), &Waveform::Square);
+        assert_eq!(scale.to_absolute(-1.0), &Waveform::Sine);
+        assert_eq!(scale.to_absolute(2.0), &Waveform::Square);
+
+        for value in scale.values() {
+            let relative = scale.to_relative(value).unwrap();
+            assert_eq!(scale.to_absolute(relative), value);
+        }
+
+        assert_eq!(scale.label(&Waveform::Saw), None);
+    }
+
+    #[test]
+    fn test_enum_labels() {
+        let scale = EnumScale::labelled(vec![(1, "1x"), (2, "2x"), (4, "4x"), (8, "8x")]).unwrap();
+
+        assert_eq!(scale.label(&4), Some("4x"));
+        assert_eq!(scale.label(&3), None);
+        assert_eq!(scale.label_at(3), Some("8x"));
+        assert_eq!(scale.label_at(4), None);
+        assert_eq!(scale.value_of_label("2x"), Some(&2));
+        assert_eq!(scale.value_of_label("16x"), None);
+        assert_eq!(scale.to_relative(&3), None);
+    }
+
+    #[test]
+    fn test_enum_relative_plumbing() {
+        let scale = EnumScale::labelled(vec![("lp", "Low Pass"), ("hp", "High Pass")]).unwrap();
+        let host: LinearScale<f64> = LinearScale::new(0.0, 1.0);
+        let stepped = SteppedScale::new(LinearScale::new(0_f64, 1_f64), scale.step_count());
+
+        assert_eq!(scale.convert_from(&host, 0.3), &"lp");
+        assert_eq!(scale.convert_from(&host, 0.7), &"hp");
+        assert_eq!(scale.convert_to(&host, &"hp"), Some(1.0));
+        assert_eq!(scale.convert_to(&host, &"bp"), None);
+
+        for r in 0..=10 {
+            let relative = r as f64 / 10.0;
+            assert_eq!(
+                scale.index_of_relative(relative),
+                stepped.step_index_of_relative(relative)
+            );
+        }
+    }
+
+    #[test]
+    fn test_enum_single_and_empty() {
+        let single = EnumScale::new(vec!['x']).unwrap();
+        assert_approx_eq!(single.to_relative(&'x').unwrap(), 0.0);
+        assert_eq!(single.to_absolute(0.7), &'x');
+
+        assert_eq!(EnumScale::<char>::new(vec![]), Err(ScaleError::NoValues));
+    }
+}
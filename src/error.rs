@@ -0,0 +1,98 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors that can occur when constructing or evaluating a scale.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScaleError {
+    /// A centre value does not lie strictly between the minimum and the maximum of the scale.
+    CentreOutOfRange,
+    /// A string could not be parsed as a note name.
+    InvalidNoteName(String),
+    /// The range of a scale contains or touches zero, but the scale is only defined for strictly positive
+    /// or strictly negative ranges.
+    RangeContainsZero,
+    /// A scale that requires at least one value was created without any.
+    NoValues,
+    /// The number of outputs does not match the number of buckets.
+    OutputCountMismatch { expected: usize, actual: usize },
+    /// Thresholds are not in ascending order.
+    UnsortedThresholds,
+    /// The relative bounds of a segment are not ascending, or do not end at 1.0.
+    InvalidSegmentBounds,
+    /// The maximum of a segment does not match the minimum of the segment following it.
+    Discontinuity { index: usize },
+    /// A numeric inversion did not find a solution within the configured tolerance and number of iterations.
+    NotConverged,
+    /// A skew factor is not a finite value greater than zero.
+    InvalidSkew,
+    /// A threshold is not a finite value greater than zero.
+    InvalidThreshold,
+    /// An interval is not a finite value greater than zero.
+    InvalidInterval,
+    /// Two scales that must share a domain have different minimums or maximums.
+    DomainMismatch,
+    /// The bounds of a range are not finite or the minimum is not less than the maximum.
+    InvalidRange,
+    /// A peak value is not a finite value greater than zero.
+    InvalidPeak,
+    /// A companding parameter is out of range, i.e. mu is not greater than zero or A is less than one.
+    InvalidCompression,
+    /// The bounds of a probability scale do not lie strictly between 0.0 and 1.0.
+    ProbabilityOutOfRange,
+}
+
+impl fmt::Display for ScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaleError::CentreOutOfRange => {
+                write!(f, "centre must lie strictly between minimum and maximum")
+            }
+            ScaleError::InvalidNoteName(name) => write!(f, "invalid note name: '{}'", name),
+            ScaleError::RangeContainsZero => {
+                write!(f, "range must be strictly positive or strictly negative")
+            }
+            ScaleError::NoValues => write!(f, "scale requires at least one value"),
+            ScaleError::OutputCountMismatch { expected, actual } => {
+                write!(f, "expected {} outputs but got {}", expected, actual)
+            }
+            ScaleError::UnsortedThresholds => write!(f, "thresholds must be in ascending order"),
+            ScaleError::InvalidSegmentBounds => write!(
+                f,
+                "segment bounds must be ascending and the last segment must end at 1.0"
+            ),
+            ScaleError::Discontinuity { index } => write!(
+                f,
+                "segment {} does not end where segment {} starts",
+                index,
+                index + 1
+            ),
+            ScaleError::NotConverged => write!(f, "numeric inversion did not converge"),
+            ScaleError::InvalidSkew => write!(f, "skew must be a finite value greater than zero"),
+            ScaleError::InvalidThreshold => {
+                write!(f, "threshold must be a finite value greater than zero")
+            }
+            ScaleError::InvalidInterval => {
+                write!(f, "interval must be a finite value greater than zero")
+            }
+            ScaleError::DomainMismatch => {
+                write!(f, "scales must share the same minimum and maximum")
+            }
+            ScaleError::InvalidRange => {
+                write!(
+                    f,
+                    "range must be finite and its minimum less than its maximum"
+                )
+            }
+            ScaleError::InvalidPeak => write!(f, "peak must be a finite value greater than zero"),
+            ScaleError::InvalidCompression => write!(
+                f,
+                "companding parameter out of range, mu must be greater than 0 and A at least 1"
+            ),
+            ScaleError::ProbabilityOutOfRange => {
+                write!(f, "probabilities must lie strictly between 0 and 1")
+            }
+        }
+    }
+}
+
+impl Error for ScaleError {}
@@ -0,0 +1,195 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+const DEFAULT_TOLERANCE: f64 = 1e-12;
+const DEFAULT_MAX_ITERATIONS: usize = 100;
+
+/// A scale defined by an arbitrary closure that maps absolute values to relative values, e.g. a sensor curve or
+/// a vendor taper.
+///
+/// The closure must be continuous and monotonically increasing. Unless an analytic inverse is provided,
+/// [`to_absolute`](Scale::to_absolute) finds the absolute value numerically using a bracketed Newton iteration
+/// that falls back to bisection. The bracket starts at the scale's range and is widened for relative values
+/// outside of it, backing off wherever the closure is not finite. If no solution is found,
+/// [`try_to_absolute`](FunctionScale::try_to_absolute) returns an error and [`to_absolute`](Scale::to_absolute)
+/// returns `N::from_float(f64::NAN)`, which is NaN for floating point types but e.g. 0 for integers. Use
+/// `try_to_absolute` to detect failed inversions.
+pub struct FunctionScale<N> {
+    min: N,
+    max: N,
+    min_f64: f64,
+    max_f64: f64,
+    forward: Box<dyn Fn(f64) -> f64>,
+    inverse: Option<Box<dyn Fn(f64) -> f64>>,
+    tolerance: f64,
+    max_iterations: usize,
+}
+
+impl<N> FunctionScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N, forward: impl Fn(f64) -> f64 + 'static) -> FunctionScale<N> {
+        FunctionScale {
+            min_f64: min.clone().to_float(),
+            max_f64: max.clone().to_float(),
+            min,
+            max,
+            forward: Box::new(forward),
+            inverse: None,
+            tolerance: DEFAULT_TOLERANCE,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    /// Use an analytic inverse instead of inverting the forward function numerically.
+    pub fn with_inverse(mut self, inverse: impl Fn(f64) -> f64 + 'static) -> FunctionScale<N> {
+        self.inverse = Some(Box::new(inverse));
+        self
+    }
+
+    /// The maximum deviation in relative space accepted by the numeric inversion, i.e. `to_absolute` succeeds
+    /// once the forward function of its result is within this distance of the requested relative value.
+    pub fn with_tolerance(mut self, tolerance: f64) -> FunctionScale<N> {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> FunctionScale<N> {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    pub fn max_iterations(&self) -> usize {
+        self.max_iterations
+    }
+
+    pub fn has_inverse(&self) -> bool {
+        self.inverse.is_some()
+    }
+
+    /// Convert a relative value to an absolute value, reporting an error if the numeric inversion does not converge.
+    pub fn try_to_absolute(&self, relative: f64) -> Result<N, ScaleError> {
+        if let Some(inverse) = &self.inverse {
+            return Ok(N::from_float(inverse(relative)));
+        }
+
+        numeric::invert_monotonic_unbounded(
+            &self.forward,
+            relative,
+            self.min_f64,
+            self.max_f64,
+            self.tolerance,
+            self.max_iterations,
+        )
+        .map(N::from_float)
+        .ok_or(ScaleError::NotConverged)
+    }
+}
+
+impl<N> Scale<N> for FunctionScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        (self.forward)(absolute.to_float())
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.try_to_absolute(relative)
+            .unwrap_or_else(|_| N::from_float(f64::NAN))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_function_numeric_inverse() {
+        let scale: FunctionScale<f64> = FunctionScale::new(0.0, 100.0, |x| (x / 100.0).powf(0.4));
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.to_relative(100.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 100.0 * 0.5f64.powf(2.5));
+        assert_approx_eq!(scale.to_absolute(1.0), 100.0);
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+
+        for i in 0..=20 {
+            let x = i as f64 * 5.0;
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(x)), x, 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_function_analytic_inverse() {
+        let scale: FunctionScale<f64> = FunctionScale::new(1.0, 1000.0, |x| x.log10() / 3.0)
+            .with_inverse(|r| 10f64.powf(3.0 * r));
+
+        assert!(scale.has_inverse());
+        assert_approx_eq!(scale.to_relative(10.0), 1.0 / 3.0);
+        assert_approx_eq!(scale.to_absolute(2.0 / 3.0), 100.0);
+    }
+
+    #[test]
+    fn test_function_out_of_range() {
+        let scale: FunctionScale<f64> =
+            FunctionScale::new(0.0, 10.0, |x| x / 10.0 + (x / 10.0).powi(3));
+
+        let relative = scale.to_relative(25.0);
+        assert_approx_eq!(scale.to_absolute(relative), 25.0, 1e-6);
+        let relative = scale.to_relative(-25.0);
+        assert_approx_eq!(scale.to_absolute(relative), -25.0, 1e-6);
+
+        let scale: FunctionScale<f64> = FunctionScale::new(1.0, 1000.0, |x| x.log10() / 3.0);
+        assert_approx_eq!(scale.try_to_absolute(-1.0 / 3.0).unwrap(), 0.1);
+        assert_approx_eq!(scale.to_absolute(scale.to_relative(0.001)), 0.001);
+    }
+
+    #[test]
+    fn test_function_not_converged() {
+        let scale: FunctionScale<f64> = FunctionScale::new(0.0, 1.0, |x| x.tanh());
+
+        assert_eq!(scale.try_to_absolute(2.0), Err(ScaleError::NotConverged));
+        assert!(scale.to_absolute(2.0).is_nan());
+
+        let scale: FunctionScale<f64> = FunctionScale::new(0.0, 1.0, |x| x.powi(5))
+            .with_tolerance(1e-15)
+            .with_max_iterations(1);
+        assert_eq!(scale.try_to_absolute(0.3), Err(ScaleError::NotConverged));
+    }
+
+    #[test]
+    fn test_function_converter() {
+        let scale: FunctionScale<f64> = FunctionScale::new(0.0, 100.0, |x| (x / 100.0).sqrt());
+        let slider: LinearScale<f64> = LinearScale::new(0.0, 200.0);
+
+        assert_approx_eq!((&slider, &scale).convert(100.0), 25.0);
+        assert_approx_eq!((&slider, &scale).convert_back(25.0), 100.0);
+    }
+
+    #[test]
+    fn test_function_discontinuous() {
+        let scale: FunctionScale<f64> =
+            FunctionScale::new(0.0, 1.0, |x| if x < 0.5 { 0.0 } else { 1.0 });
+
+        assert_eq!(scale.try_to_absolute(0.5), Err(ScaleError::NotConverged));
+        assert!(scale.to_absolute(0.5).is_nan());
+        assert_eq!(scale.try_to_absolute(1.0), Ok(1.0));
+    }
+}
@@ -0,0 +1,113 @@
+use super::convert::*;
+use super::*;
+
+/// An adapter that flips the relative values of another scale, so that its minimum maps to relative 1.0
+/// and its maximum to relative 0.0.
+///
+/// Absolute values, including [`min`](Scale::min) and [`max`](Scale::max), are the same as those of the
+/// wrapped scale. Any scale can be inverted using [`ScaleExt::inverted`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Inverted<S> {
+    inner: S,
+}
+
+impl<S> Inverted<S> {
+    pub fn new(inner: S) -> Inverted<S> {
+        Inverted { inner }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<N, S> Scale<N> for Inverted<S>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        1.0 - self.inner.to_relative(absolute)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.inner.to_absolute(1.0 - relative)
+    }
+
+    fn max(&self) -> N {
+        self.inner.max()
+    }
+
+    fn min(&self) -> N {
+        self.inner.min()
+    }
+
+    fn to_clamped_relative(&self, absolute: N) -> f64 {
+        1.0 - self.inner.to_clamped_relative(absolute)
+    }
+
+    fn to_clamped_absolute(&self, relative: f64) -> N {
+        self.inner.to_clamped_absolute(1.0 - relative)
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        -self
+            .inner
+            .to_relative_delta(absolute_delta, 1.0 - relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        self.inner.to_absolute_delta(-relative_delta, absolute_pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_inverted() {
+        let scale = LinearScale::new(0_f64, 100_f64).inverted();
+
+        assert_approx_eq!(scale.to_relative(0.0), 1.0);
+        assert_approx_eq!(scale.to_relative(25.0), 0.75);
+        assert_approx_eq!(scale.to_absolute(0.75), 25.0);
+        assert_approx_eq!(scale.to_clamped_relative(-10.0), 1.0);
+        assert_approx_eq!(scale.to_clamped_absolute(2.0), 0.0);
+        assert_eq!(scale.min(), 0.0);
+        assert_eq!(scale.max(), 100.0);
+
+        assert_approx_eq!(scale.to_relative_delta(10.0, 0.5), -0.1);
+        assert_approx_eq!(scale.to_absolute_delta(0.1, 50.0), -10.0);
+
+        assert_eq!(
+            scale.inverted().into_inner(),
+            LinearScale::new(0.0, 100.0).inverted()
+        );
+    }
+
+    #[test]
+    fn test_inverted_cyclic_delta() {
+        let scale = CyclicScale::new(0_f64, 360_f64).inverted();
+
+        assert_approx_eq!(scale.to_relative_delta(20.0, 0.05), -20.0 / 360.0);
+        assert_approx_eq!(scale.to_absolute_delta(0.1, 350.0), -36.0);
+    }
+
+    #[test]
+    fn test_inverted_broken() {
+        let scale = BrokenScale::inverted(0_f64, 100_f64, &[(20.0, 0.5)]);
+
+        assert_approx_eq!(scale.to_relative(20.0), 0.5);
+        assert_approx_eq!(scale.to_relative(10.0), 0.75);
+        assert_approx_eq!(scale.to_relative(60.0), 0.25);
+        assert_approx_eq!(scale.to_absolute(0.75), 10.0);
+        assert_approx_eq!(scale.to_absolute(0.0), 100.0);
+    }
+}
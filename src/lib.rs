@@ -1,10 +1,35 @@
 pub mod prelude;
 
+mod band;
 mod broken;
+mod bucket;
+mod companding;
 mod convert;
 mod converter;
+mod curve;
+mod cyclic;
+mod decibel;
+mod diverging;
+mod enumerated;
+mod error;
+mod function;
+mod inverted;
 mod linear;
 mod logarithmic;
+mod morph;
+mod numeric;
+mod open_ended;
+mod piecewise;
+mod pitch;
+mod power;
+mod probability;
+mod psychoacoustic;
+mod reciprocal;
+mod sigmoid;
+mod stepped;
+mod symlog;
+mod transfer;
+mod viewport;
 
 use convert::*;
 use std::cell::RefCell;
@@ -40,19 +65,13 @@ where
 
     fn to_clamped_absolute(&self, relative: f64) -> N {
         let relative: f64 = relative.to_float();
-        let relative = if relative > 1.0 {
-            1.0
-        } else if relative < 0.0 {
-            0.0
-        } else {
-            relative
-        };
+        let relative = relative.clamp(0.0, 1.0);
 
         self.to_absolute(relative)
     }
 
     fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
-        let absolute_pos = self.to_absolute(relative_pos.clone());
+        let absolute_pos = self.to_absolute(relative_pos);
         let rel_pos_out = self.to_relative(absolute_pos + absolute_delta);
         rel_pos_out - relative_pos
     }
@@ -64,10 +83,41 @@ where
     }
 }
 
+/// Adapters that are available on every [`Scale`].
+pub trait ScaleExt<N>: Scale<N> + Sized
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Flip the relative values of this scale, see [`Inverted`](crate::inverted::Inverted).
+    fn inverted(self) -> crate::inverted::Inverted<Self> {
+        crate::inverted::Inverted::new(self)
+    }
+
+    /// Apply a relative curve to this scale, see [`Curved`](crate::curve::Curved).
+    fn curved<C>(self, curve: C) -> crate::curve::Curved<Self, C>
+    where
+        C: crate::curve::RelativeCurve,
+    {
+        crate::curve::Curved::new(self, curve)
+    }
+
+    /// Show a zoomable window of this scale, see [`Viewport`](crate::viewport::Viewport).
+    fn viewport(self) -> crate::viewport::Viewport<Self> {
+        crate::viewport::Viewport::new(self)
+    }
+}
+
+impl<N, S> ScaleExt<N> for S
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+}
+
 impl<N, SN> Scale<N> for &SN
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -84,12 +134,28 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_clamped_relative(&self, absolute: N) -> f64 {
+        SN::to_clamped_relative(self, absolute)
+    }
+
+    fn to_clamped_absolute(&self, relative: f64) -> N {
+        SN::to_clamped_absolute(self, relative)
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
 }
 
 impl<N, SN> Scale<N> for Box<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -106,12 +172,28 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_clamped_relative(&self, absolute: N) -> f64 {
+        SN::to_clamped_relative(self, absolute)
+    }
+
+    fn to_clamped_absolute(&self, relative: f64) -> N {
+        SN::to_clamped_absolute(self, relative)
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
 }
 
 impl<N, SN> Scale<N> for Rc<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -128,12 +210,28 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_clamped_relative(&self, absolute: N) -> f64 {
+        SN::to_clamped_relative(self, absolute)
+    }
+
+    fn to_clamped_absolute(&self, relative: f64) -> N {
+        SN::to_clamped_absolute(self, relative)
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
 }
 
 impl<N, SN> Scale<N> for RefCell<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self.borrow().deref(), absolute)
@@ -150,12 +248,28 @@ where
     fn min(&self) -> N {
         SN::min(self.borrow().deref())
     }
+
+    fn to_clamped_relative(&self, absolute: N) -> f64 {
+        SN::to_clamped_relative(self.borrow().deref(), absolute)
+    }
+
+    fn to_clamped_absolute(&self, relative: f64) -> N {
+        SN::to_clamped_absolute(self.borrow().deref(), relative)
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self.borrow().deref(), absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self.borrow().deref(), relative_delta, absolute_pos)
+    }
 }
 
 impl<N, SN> Scale<N> for Arc<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -172,6 +286,22 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_clamped_relative(&self, absolute: N) -> f64 {
+        SN::to_clamped_relative(self, absolute)
+    }
+
+    fn to_clamped_absolute(&self, relative: f64) -> N {
+        SN::to_clamped_absolute(self, relative)
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use super::convert::*;
+use super::inverted::*;
 use super::*;
 /// A linear scale implementation with a fixed minimum and maximum that can optionally be inverted.
 #[derive(Debug, Clone, PartialEq)]
@@ -7,7 +8,6 @@ pub struct LinearScale<N> {
     max: N,
     min_f64: f64,
     full_range: f64,
-    inverted: bool,
 }
 
 impl<N> LinearScale<N>
@@ -24,22 +24,11 @@ where
             max,
             min_f64,
             full_range,
-            inverted: false,
         }
     }
 
-    pub fn inverted(min: N, max: N) -> LinearScale<N> {
-        let min_f64 = min.clone().to_float();
-        let max_f64 = max.clone().to_float();
-        let full_range = max_f64 - min_f64;
-
-        LinearScale {
-            min,
-            max,
-            min_f64,
-            full_range,
-            inverted: true,
-        }
+    pub fn inverted(min: N, max: N) -> Inverted<LinearScale<N>> {
+        Inverted::new(LinearScale::new(min, max))
     }
 }
 
@@ -50,21 +39,10 @@ where
     fn to_relative(&self, absolute: N) -> f64 {
         let absolute = absolute.to_float();
         let partial_range = absolute - self.min_f64;
-
-        if self.inverted {
-            1.0 - (partial_range / self.full_range)
-        } else {
-            partial_range / self.full_range
-        }
+        partial_range / self.full_range
     }
 
     fn to_absolute(&self, relative: f64) -> N {
-        let relative: f64 = if self.inverted {
-            1.0 - relative
-        } else {
-            relative
-        };
-
         let partial = relative * self.full_range;
         let abs = self.min_f64 + partial;
         N::from_float(abs)
@@ -89,7 +67,6 @@ where
 {
     min: Min,
     max: Max,
-    inverted: bool,
 }
 
 impl<N, Min, Max> DynamicLinearScale<N, Min, Max>
@@ -99,19 +76,11 @@ where
     Max: Fn() -> N,
 {
     pub fn new(min: Min, max: Max) -> DynamicLinearScale<N, Min, Max> {
-        DynamicLinearScale {
-            min,
-            max,
-            inverted: false,
-        }
+        DynamicLinearScale { min, max }
     }
 
-    pub fn inverted(min: Min, max: Max) -> DynamicLinearScale<N, Min, Max> {
-        DynamicLinearScale {
-            min,
-            max,
-            inverted: true,
-        }
+    pub fn inverted(min: Min, max: Max) -> Inverted<DynamicLinearScale<N, Min, Max>> {
+        Inverted::new(DynamicLinearScale::new(min, max))
     }
 }
 
@@ -130,20 +99,10 @@ where
         let partial_range = absolute - min;
         let full_range = max - min;
 
-        if self.inverted {
-            1.0 - (partial_range / full_range)
-        } else {
-            partial_range / full_range
-        }
+        partial_range / full_range
     }
 
     fn to_absolute(&self, relative: f64) -> N {
-        let relative: f64 = if self.inverted {
-            1.0 - relative
-        } else {
-            relative
-        };
-
         let min = self.min().to_float();
         let max = self.min().to_float();
 
@@ -257,7 +216,7 @@ mod tests {
 
     #[test]
     fn test_inverted() {
-        let scale: LinearScale<f64> = LinearScale::inverted(0.0, 100.0);
+        let scale: Inverted<LinearScale<f64>> = LinearScale::inverted(0.0, 100.0);
 
         assert_approx_eq!(scale.to_relative(0.0), 1.0);
         assert_approx_eq!(scale.to_relative(100.0), 0.0);
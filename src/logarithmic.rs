@@ -1,4 +1,5 @@
 use super::convert::*;
+use super::inverted::*;
 use super::linear::*;
 use super::*;
 
@@ -6,7 +7,9 @@ use super::*;
 pub struct LogarithmicScale<N> {
     min: N,
     max: N,
-    linear_delegate: LinearScale<N>,
+    base: f64,
+    offset: f64,
+    linear_delegate: LinearScale<f64>,
 }
 
 impl<N> LogarithmicScale<N>
@@ -14,22 +17,45 @@ where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
 {
     pub fn new(min: N, max: N) -> LogarithmicScale<N> {
-        LogarithmicScale {
-            min: min.clone(),
-            max: max.clone(),
-            linear_delegate: LinearScale::new(apply_to(min, f64::log10), apply_to(max, f64::log10)),
-        }
+        LogarithmicScale::with_base_and_offset(min, max, 10.0, 0.0)
+    }
+
+    pub fn inverted(min: N, max: N) -> Inverted<LogarithmicScale<N>> {
+        Inverted::new(LogarithmicScale::new(min, max))
+    }
+
+    /// Create a logarithmic scale using the provided base, e.g. 2.0 or `std::f64::consts::E`.
+    /// The base does not change the relative values produced by the scale.
+    pub fn with_base(min: N, max: N, base: f64) -> LogarithmicScale<N> {
+        LogarithmicScale::with_base_and_offset(min, max, base, 0.0)
+    }
+
+    /// Create a scale that is logarithmic in `x + offset`. This allows ranges that include zero,
+    /// e.g. 0 to 20000 Hz with an offset of 1.0.
+    pub fn with_offset(min: N, max: N, offset: f64) -> LogarithmicScale<N> {
+        LogarithmicScale::with_base_and_offset(min, max, 10.0, offset)
     }
-    pub fn inverted(min: N, max: N) -> LogarithmicScale<N> {
+
+    pub fn with_base_and_offset(min: N, max: N, base: f64, offset: f64) -> LogarithmicScale<N> {
         LogarithmicScale {
             min: min.clone(),
             max: max.clone(),
-            linear_delegate: LinearScale::inverted(
-                apply_to(min, f64::log10),
-                apply_to(max, f64::log10),
+            base,
+            offset,
+            linear_delegate: LinearScale::new(
+                log(min.to_float() + offset, base),
+                log(max.to_float() + offset, base),
             ),
         }
     }
+
+    pub fn base(&self) -> f64 {
+        self.base
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
 }
 
 impl<N> Scale<N> for LogarithmicScale<N>
@@ -37,13 +63,13 @@ where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
 {
     fn to_relative(&self, absolute: N) -> f64 {
-        let abs_log = apply_to(absolute, f64::log10);
+        let abs_log = log(absolute.to_float() + self.offset, self.base);
         self.linear_delegate.to_relative(abs_log)
     }
 
     fn to_absolute(&self, relative: f64) -> N {
         let abs_log = self.linear_delegate.to_absolute(relative);
-        apply_to(abs_log, |f| 10f64.powf(f))
+        N::from_float(exp(abs_log, self.base) - self.offset)
     }
 
     fn max(&self) -> N {
@@ -55,11 +81,26 @@ where
     }
 }
 
-fn apply_to<N>(n: N, fun: impl Fn(f64) -> f64) -> N
-where
-    N: ToFloat<f64> + FromFloat<f64>,
-{
-    N::from_float(fun(n.to_float()))
+fn log(f: f64, base: f64) -> f64 {
+    if base == 10.0 {
+        f.log10()
+    } else if base == 2.0 {
+        f.log2()
+    } else if base == std::f64::consts::E {
+        f.ln()
+    } else {
+        f.log(base)
+    }
+}
+
+fn exp(f: f64, base: f64) -> f64 {
+    if base == 2.0 {
+        f.exp2()
+    } else if base == std::f64::consts::E {
+        f.exp()
+    } else {
+        base.powf(f)
+    }
 }
 
 #[cfg(test)]
@@ -99,7 +140,7 @@ mod tests {
 
     #[test]
     fn test_log_inverted() {
-        let scale: LogarithmicScale<f64> = LogarithmicScale::inverted(10.0, 10240.0);
+        let scale: Inverted<LogarithmicScale<f64>> = LogarithmicScale::inverted(10.0, 10240.0);
         assert_approx_eq!(scale.to_absolute(0.0), 10240.0);
         assert_approx_eq!(scale.to_absolute(0.1), 5120.0);
         assert_approx_eq!(scale.to_absolute(0.2), 2560.0);
@@ -149,6 +190,55 @@ mod tests {
         assert_approx_eq!(scale.to_clamped_relative(20240.0), 1.0);
     }
 
+    #[test]
+    fn test_log_base() {
+        let log10: LogarithmicScale<f64> = LogarithmicScale::new(10.0, 10240.0);
+        let log2: LogarithmicScale<f64> = LogarithmicScale::with_base(10.0, 10240.0, 2.0);
+        let ln: LogarithmicScale<f64> =
+            LogarithmicScale::with_base(10.0, 10240.0, std::f64::consts::E);
+        let log7: LogarithmicScale<f64> = LogarithmicScale::with_base(10.0, 10240.0, 7.0);
+
+        for scale in &[&log2, &ln, &log7] {
+            for abs in &[1.0, 10.0, 20.0, 320.0, 5120.0, 10240.0, 20480.0] {
+                assert_approx_eq!(scale.to_relative(*abs), log10.to_relative(*abs));
+            }
+            for rel in &[-0.5, 0.0, 0.1, 0.5, 0.9, 1.0, 1.1] {
+                assert_approx_eq!(scale.to_absolute(*rel), log10.to_absolute(*rel), 1e-9);
+            }
+        }
+
+        assert_approx_eq!(log2.base(), 2.0);
+    }
+
+    #[test]
+    fn test_log_offset() {
+        let scale: LogarithmicScale<f64> = LogarithmicScale::with_offset(0.0, 20_000.0, 1.0);
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.to_relative(20_000.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 20_001f64.sqrt() - 1.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 20_000.0);
+        assert_approx_eq!(scale.to_relative(scale.to_absolute(0.3)), 0.3);
+
+        let millis: LogarithmicScale<f64> =
+            LogarithmicScale::with_base_and_offset(0.0, 1_000.0, 2.0, 10.0);
+        assert_approx_eq!(millis.to_relative(0.0), 0.0);
+        assert_approx_eq!(millis.to_absolute(1.0), 1_000.0);
+        assert_approx_eq!(millis.to_relative(90.0), 0.4989220);
+    }
+
+    #[test]
+    fn test_log_offset_integer() {
+        let scale: LogarithmicScale<u32> = LogarithmicScale::with_offset(0, 20_000, 1.0);
+
+        assert_eq!(scale.to_absolute(0.0), 0);
+        assert_eq!(scale.to_absolute(0.5), 140);
+        assert_eq!(scale.to_absolute(1.0), 20_000);
+        assert_approx_eq!(scale.to_relative(1_000), 0.6976047);
+        assert_approx_eq!(scale.to_relative(20_000), 1.0);
+    }
+
     // #[test]
     fn _benchmark() {
         let loops = 100_000_000;
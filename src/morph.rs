@@ -0,0 +1,296 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+const INVERSION_TOLERANCE: f64 = 1e-12;
+const INVERSION_MAX_ITERATIONS: usize = 100;
+const DOMAIN_TOLERANCE: f64 = 1e-9;
+
+/// A scale that blends two scales over the same domain, e.g. to fade a parameter's response from linear
+/// to logarithmic.
+///
+/// The relative value is interpolated between the relative values of both scales according to the morph factor,
+/// 0.0 being identical to the first scale and 1.0 being identical to the second one. Since a blend of two monotonic
+/// scales is monotonic as well, [`to_absolute`](Scale::to_absolute) can invert it numerically for morph factors
+/// in between. Both scales must share the same [`min`](Scale::min) and [`max`](Scale::max).
+///
+/// For integer types the blend only changes at whole numbers, so it is interpolated linearly between neighbouring
+/// integers for the inversion and the result is rounded to the nearest integer.
+///
+/// If the numeric inversion fails, e.g. for relative values that no absolute value maps to,
+/// [`try_to_absolute`](Morph::try_to_absolute) returns an error and [`to_absolute`](Scale::to_absolute) returns
+/// `N::from_float(f64::NAN)`, which is NaN for floating point types but e.g. 0 for integers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Morph<A, B> {
+    a: A,
+    b: B,
+    morph: f64,
+}
+
+impl<A, B> Morph<A, B> {
+    /// Create a morph between two scales. The morph factor is clamped to 0.0 to 1.0.
+    /// Fails if the scales do not share the same minimum and maximum.
+    pub fn new<N>(a: A, b: B, morph: f64) -> Result<Morph<A, B>, ScaleError>
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        A: Scale<N>,
+        B: Scale<N>,
+    {
+        let same = |x: N, y: N| {
+            let (x, y) = (x.to_float(), y.to_float());
+            x == y || (x - y).abs() <= DOMAIN_TOLERANCE * x.abs().max(y.abs()).max(1.0)
+        };
+        if !same(a.min(), b.min()) || !same(a.max(), b.max()) {
+            return Err(ScaleError::DomainMismatch);
+        }
+
+        Ok(Morph {
+            a,
+            b,
+            morph: morph.clamp(0.0, 1.0),
+        })
+    }
+
+    pub fn a(&self) -> &A {
+        &self.a
+    }
+
+    pub fn b(&self) -> &B {
+        &self.b
+    }
+
+    pub fn morph(&self) -> f64 {
+        self.morph
+    }
+
+    /// Change the morph factor, clamped to 0.0 to 1.0.
+    pub fn set_morph(&mut self, morph: f64) {
+        self.morph = morph.clamp(0.0, 1.0);
+    }
+
+    /// Convert a relative value to an absolute value, reporting an error if the numeric inversion does not converge.
+    pub fn try_to_absolute<N>(&self, relative: f64) -> Result<N, ScaleError>
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        A: Scale<N>,
+        B: Scale<N>,
+    {
+        if self.morph == 0.0 {
+            return Ok(self.a.to_absolute(relative));
+        }
+        if self.morph == 1.0 {
+            return Ok(self.b.to_absolute(relative));
+        }
+
+        let blend = |x: f64| self.to_relative(N::from_float(x));
+        let (min, max) = (self.a.min().to_float(), self.a.max().to_float());
+
+        if N::from_float(0.5).to_float() == 0.5 {
+            return numeric::invert_monotonic_unbounded(
+                blend,
+                relative,
+                min,
+                max,
+                INVERSION_TOLERANCE,
+                INVERSION_MAX_ITERATIONS,
+            )
+            .map(N::from_float)
+            .ok_or(ScaleError::NotConverged);
+        }
+
+        let interpolated = |x: f64| {
+            let whole = x.floor();
+            let fraction = x - whole;
+            if fraction == 0.0 {
+                blend(whole)
+            } else {
+                let lower = blend(whole);
+                lower + fraction * (blend(whole + 1.0) - lower)
+            }
+        };
+        numeric::invert_monotonic_unbounded(
+            interpolated,
+            relative,
+            min,
+            max,
+            INVERSION_TOLERANCE,
+            INVERSION_MAX_ITERATIONS,
+        )
+        .map(|x| N::from_float(x.round()))
+        .ok_or(ScaleError::NotConverged)
+    }
+}
+
+impl<N, A, B> Scale<N> for Morph<A, B>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    A: Scale<N>,
+    B: Scale<N>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        if self.morph == 0.0 {
+            return self.a.to_relative(absolute);
+        }
+        if self.morph == 1.0 {
+            return self.b.to_relative(absolute);
+        }
+
+        let a = self.a.to_relative(absolute.clone());
+        let b = self.b.to_relative(absolute);
+        a + self.morph * (b - a)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.try_to_absolute(relative)
+            .unwrap_or_else(|_| N::from_float(f64::NAN))
+    }
+
+    fn max(&self) -> N {
+        self.a.max()
+    }
+
+    fn min(&self) -> N {
+        self.a.min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    fn morph(factor: f64) -> Morph<LinearScale<f64>, LogarithmicScale<f64>> {
+        Morph::new(
+            LinearScale::new(20.0, 20_000.0),
+            LogarithmicScale::new(20.0, 20_000.0),
+            factor,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_morph_ends() {
+        let linear = morph(0.0);
+        assert_approx_eq!(linear.to_relative(10_010.0), 0.5);
+        assert_approx_eq!(linear.to_absolute(0.5), 10_010.0);
+
+        let log = morph(1.0);
+        assert_approx_eq!(log.to_relative(2_000.0), 2.0 / 3.0);
+        assert_approx_eq!(log.to_absolute(2.0 / 3.0), 2_000.0);
+    }
+
+    #[test]
+    fn test_morph_blend() {
+        let scale = morph(0.5);
+
+        let linear = (2_000.0 - 20.0) / 19_980.0;
+        assert_approx_eq!(scale.to_relative(2_000.0), 0.5 * (linear + 2.0 / 3.0));
+        assert_approx_eq!(scale.to_relative(20.0), 0.0);
+        assert_approx_eq!(scale.to_relative(20_000.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.0), 20.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 20_000.0);
+        assert_eq!(scale.min(), 20.0);
+        assert_eq!(scale.max(), 20_000.0);
+    }
+
+    #[test]
+    fn test_morph_round_trip() {
+        let mut scale = morph(0.0);
+
+        for m in 0..=10 {
+            scale.set_morph(m as f64 / 10.0);
+            for i in 0..=50 {
+                let x = 20.0 + i as f64 * 19_980.0 / 50.0;
+                let relative = scale.to_relative(x);
+                assert_approx_eq!(scale.to_absolute(relative), x, 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_morph_clamped_factor() {
+        let mut scale = morph(2.0);
+        assert_eq!(scale.morph(), 1.0);
+
+        scale.set_morph(-1.0);
+        assert_eq!(scale.morph(), 0.0);
+    }
+
+    #[test]
+    fn test_morph_converter() {
+        let scale = morph(0.25);
+        let knob: LinearScale<f64> = LinearScale::new(0.0, 1.0);
+
+        let position = (&knob, &scale).convert_back(1_000.0);
+        assert_approx_eq!((&knob, &scale).convert(position), 1_000.0, 1e-6);
+    }
+
+    #[test]
+    fn test_morph_domain_mismatch() {
+        let mismatch = Morph::new(
+            LinearScale::new(0_f64, 10_f64),
+            LinearScale::new(0_f64, 100_f64),
+            0.5,
+        );
+        assert_eq!(mismatch.err(), Some(ScaleError::DomainMismatch));
+
+        let mismatch = Morph::new(
+            LinearScale::new(1_f64, 10_f64),
+            LogarithmicScale::new(2_f64, 10_f64),
+            0.5,
+        );
+        assert_eq!(mismatch.err(), Some(ScaleError::DomainMismatch));
+    }
+
+    #[test]
+    fn test_morph_below_range() {
+        let scale = morph(0.5);
+
+        let relative = scale.to_relative(0.02);
+        assert!(relative < -0.5);
+        assert_approx_eq!(scale.to_absolute(relative), 0.02);
+
+        let absolute: f64 = scale.try_to_absolute(-0.5).unwrap();
+        assert_approx_eq!(scale.to_relative(absolute), -0.5);
+    }
+
+    #[test]
+    fn test_morph_failed_inversion() {
+        let scale = Morph::new(
+            LogarithmicScale::new(1_f64, 1_000_f64),
+            LogarithmicScale::new(1_f64, 1_000_f64),
+            0.5,
+        )
+        .unwrap();
+
+        let result: Result<f64, ScaleError> = scale.try_to_absolute(f64::NAN);
+        assert_eq!(result, Err(ScaleError::NotConverged));
+        assert!(scale.to_absolute(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_morph_integer() {
+        let scale = Morph::new(
+            LinearScale::new(20_u32, 20_000_u32),
+            LogarithmicScale::new(20_u32, 20_000_u32),
+            0.5,
+        )
+        .unwrap();
+
+        assert_eq!(scale.to_absolute(0.0), 20);
+        assert_eq!(scale.to_absolute(1.0), 20_000);
+        for x in [20_u32, 21, 100, 1_000, 1_001, 5_000, 19_999, 20_000] {
+            assert_eq!(scale.to_absolute(scale.to_relative(x)), x);
+        }
+
+        for relative in [0.25, 0.5, 0.75] {
+            let absolute: u32 = scale.to_absolute(relative);
+            assert!(scale.to_relative(absolute - 1) < relative);
+            assert!(scale.to_relative(absolute + 1) > relative);
+        }
+
+        let below: Result<u32, ScaleError> = scale.try_to_absolute(-0.5);
+        assert_eq!(below, Err(ScaleError::NotConverged));
+    }
+}
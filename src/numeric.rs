@@ -0,0 +1,192 @@
+/// Find `x` within `lower..=upper` so that `f(x)` equals `target` within `tolerance`, for a continuous function `f`
+/// that is monotonic (increasing or decreasing) on that interval.
+///
+/// This is a safeguarded Newton iteration: Newton steps use a finite difference derivative and fall back to
+/// bisection whenever a step would leave the current bracket. The tolerance only applies to `f(x)`, there is no
+/// tolerance on `x` itself. Returns `None` if the target is not bracketed by `f(lower)` and `f(upper)`, if the
+/// iteration does not converge within `max_iterations` or if the bracket shrinks to a single floating point
+/// value without meeting the tolerance.
+pub(crate) fn invert_monotonic<F>(
+    f: F,
+    target: f64,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut lo, mut hi) = (lower, upper);
+    let mut f_lo = f(lo) - target;
+    let f_hi = f(hi) - target;
+
+    if f_lo.abs() <= tolerance {
+        return Some(lo);
+    }
+    if f_hi.abs() <= tolerance {
+        return Some(hi);
+    }
+    if f_lo.is_nan() || f_hi.is_nan() || f_lo.signum() == f_hi.signum() {
+        return None;
+    }
+
+    let mut x = lo + (hi - lo) * f_lo / (f_lo - f_hi);
+    for _ in 0..max_iterations {
+        let f_x = f(x) - target;
+        if f_x.is_nan() {
+            return None;
+        }
+        if f_x.abs() <= tolerance {
+            return Some(x);
+        }
+
+        if f_x.signum() == f_lo.signum() {
+            lo = x;
+            f_lo = f_x;
+        } else {
+            hi = x;
+        }
+
+        // once the bracket cannot be narrowed any further, no point in it satisfies the tolerance,
+        // e.g. because `f` is discontinuous at the target
+        let mid = lo + 0.5 * (hi - lo);
+        if mid == lo || mid == hi {
+            return None;
+        }
+
+        let h = (hi - lo) * 1e-6;
+        let derivative = (f(x + h) - f(x - h)) / (2.0 * h);
+        let newton = x - f_x / derivative;
+        let in_bracket = (newton - lo) * (newton - hi) < 0.0;
+
+        x = if derivative.is_finite() && derivative != 0.0 && in_bracket {
+            newton
+        } else {
+            mid
+        };
+    }
+
+    None
+}
+
+/// Like [`invert_monotonic`], but for targets that lie outside of `f(lower)..f(upper)` the bracket is repeatedly
+/// widened in the direction of the target, doubling its width each time, before giving up.
+///
+/// If `f` is not finite at a widened bound, e.g. because the bound left the domain of a logarithm, the step is
+/// halved back towards the last finite bound instead. Every widening or halving counts towards `max_iterations`.
+pub(crate) fn invert_monotonic_unbounded<F>(
+    f: F,
+    target: f64,
+    lower: f64,
+    upper: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut lower, mut upper) = (lower, upper);
+    let mut width = upper - lower;
+    let increasing = f(upper) >= f(lower);
+
+    for _ in 0..max_iterations {
+        if let Some(x) = invert_monotonic(&f, target, lower, upper, tolerance, max_iterations) {
+            return Some(x);
+        }
+
+        let (f_lower, f_upper) = (f(lower), f(upper));
+        let below = if increasing {
+            target < f_lower
+        } else {
+            target > f_lower
+        };
+        let above = if increasing {
+            target > f_upper
+        } else {
+            target < f_upper
+        };
+
+        let (from, candidate) = if below {
+            (lower, lower - width)
+        } else if above {
+            (upper, upper + width)
+        } else {
+            return None;
+        };
+
+        if candidate == from {
+            return None;
+        }
+        if !f(candidate).is_finite() {
+            width *= 0.5;
+            continue;
+        }
+
+        if below {
+            upper = lower;
+            lower = candidate;
+        } else {
+            lower = upper;
+            upper = candidate;
+        }
+        width *= 2.0;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_invert_monotonic() {
+        let x = invert_monotonic(|x| x * x * x, 8.0, 0.0, 10.0, 1e-12, 100).unwrap();
+        assert_approx_eq!(x, 2.0);
+
+        let x = invert_monotonic(|x| -x.exp(), -1.0, -5.0, 5.0, 1e-12, 100).unwrap();
+        assert_approx_eq!(x, 0.0);
+
+        assert_eq!(invert_monotonic(|x| x, 2.0, 0.0, 1.0, 1e-12, 100), None);
+        assert_eq!(
+            invert_monotonic(|x| x.sqrt(), 0.5, 0.0, 1.0, 1e-12, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invert_monotonic_unbounded() {
+        let x = invert_monotonic_unbounded(|x| x * x * x, 1000.0, 0.0, 1.0, 1e-9, 100).unwrap();
+        assert_approx_eq!(x, 10.0);
+
+        let x = invert_monotonic_unbounded(|x| -x, 5.0, 0.0, 1.0, 1e-12, 100).unwrap();
+        assert_approx_eq!(x, -5.0);
+
+        assert_eq!(
+            invert_monotonic_unbounded(|x: f64| x.tanh(), 2.0, 0.0, 1.0, 1e-12, 100),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invert_monotonic_unbounded_partial_domain() {
+        let x =
+            invert_monotonic_unbounded(|x: f64| x.log10(), -1.0, 1.0, 1000.0, 1e-12, 100).unwrap();
+        assert_approx_eq!(x, 0.1);
+
+        let x = invert_monotonic_unbounded(|x: f64| x.ln(), -3.0, 1.0, 2.0, 1e-12, 100).unwrap();
+        assert_approx_eq!(x, (-3.0_f64).exp());
+
+        let x =
+            invert_monotonic_unbounded(|x: f64| -x.log10(), 2.0, 1.0, 10.0, 1e-12, 100).unwrap();
+        assert_approx_eq!(x, 0.01);
+
+        assert_eq!(
+            invert_monotonic_unbounded(|x: f64| x.sqrt(), -1.0, 1.0, 4.0, 1e-12, 100),
+            None
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A scale whose upper end is infinite, e.g. for compressor ratios from 1:1 to inf:1 or "infinite" hold times.
+///
+/// Between relative 0.0 and 1.0 the absolute value approaches infinity along `min + s * r / (1 - r)`,
+/// where `s` is chosen so that the provided centre value lands at relative 0.5.
+/// Relative values of 1.0 and above map to `max()`, which is `N::from_float(f64::INFINITY)`, i.e. positive
+/// infinity for floating point types and the saturated maximum value, acting as sentinel, for integral types.
+/// Conversely every absolute value at or above `max()` maps to exactly relative 1.0, so the infinite end
+/// round-trips, can be clamped to and can be converted back from like any other value.
+/// Below `min()` the scale is extended linearly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenEndedScale<N> {
+    min: N,
+    max: N,
+    min_f64: f64,
+    spread: f64,
+}
+
+impl<N> OpenEndedScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a new open ended scale starting at `min`, where `centre` lands at relative 0.5.
+    /// Fails if the centre is not greater than the minimum or lies at the infinite end.
+    pub fn new(min: N, centre: N) -> Result<OpenEndedScale<N>, ScaleError> {
+        let min_f64 = min.clone().to_float();
+        let max = N::from_float(f64::INFINITY);
+        let spread = centre.clone().to_float() - min_f64;
+        if !spread.is_finite() || spread <= 0.0 || centre >= max {
+            return Err(ScaleError::CentreOutOfRange);
+        }
+
+        Ok(OpenEndedScale {
+            min,
+            max,
+            min_f64,
+            spread,
+        })
+    }
+
+    /// The absolute value at relative 0.5.
+    pub fn centre(&self) -> N {
+        N::from_float(self.min_f64 + self.spread)
+    }
+
+    /// Check whether an absolute value is the infinite end of this scale.
+    pub fn is_open_end(&self, absolute: &N) -> bool {
+        absolute >= &self.max
+    }
+}
+
+impl<N> Scale<N> for OpenEndedScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        if self.is_open_end(&absolute) {
+            return 1.0;
+        }
+
+        let partial = absolute.to_float() - self.min_f64;
+        if partial < 0.0 {
+            partial / self.spread
+        } else {
+            partial / (partial + self.spread)
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        if relative >= 1.0 {
+            self.max()
+        } else if relative < 0.0 {
+            N::from_float(self.min_f64 + relative * self.spread)
+        } else {
+            N::from_float(self.min_f64 + self.spread * relative / (1.0 - relative))
+        }
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_open_ended() {
+        let ratio: OpenEndedScale<f64> = OpenEndedScale::new(1.0, 4.0).unwrap();
+
+        assert_approx_eq!(ratio.to_relative(1.0), 0.0);
+        assert_approx_eq!(ratio.to_relative(4.0), 0.5);
+        assert_approx_eq!(ratio.to_relative(10.0), 0.75);
+        assert_eq!(ratio.to_relative(f64::INFINITY), 1.0);
+
+        assert_approx_eq!(ratio.to_absolute(0.0), 1.0);
+        assert_approx_eq!(ratio.to_absolute(0.5), 4.0);
+        assert_approx_eq!(ratio.to_absolute(0.75), 10.0);
+        assert_eq!(ratio.to_absolute(1.0), f64::INFINITY);
+
+        assert_eq!(ratio.min(), 1.0);
+        assert_eq!(ratio.max(), f64::INFINITY);
+        assert_approx_eq!(ratio.centre(), 4.0);
+        assert!(ratio.is_open_end(&f64::INFINITY));
+        assert!(!ratio.is_open_end(&1e300));
+
+        let mut previous = 0.0;
+        for i in 1..100 {
+            let absolute = ratio.to_absolute(i as f64 / 100.0);
+            assert!(absolute > previous);
+            previous = absolute;
+        }
+    }
+
+    #[test]
+    fn test_open_ended_clamping() {
+        let ratio: OpenEndedScale<f64> = OpenEndedScale::new(1.0, 4.0).unwrap();
+
+        assert_approx_eq!(ratio.to_relative(0.0), -1.0 / 3.0);
+        assert_approx_eq!(ratio.to_absolute(-1.0 / 3.0), 0.0);
+        assert_eq!(ratio.to_absolute(1.5), f64::INFINITY);
+
+        assert_approx_eq!(ratio.to_clamped_relative(0.0), 0.0);
+        assert_eq!(ratio.to_clamped_relative(f64::INFINITY), 1.0);
+        assert_approx_eq!(ratio.to_clamped_absolute(-1.0), 1.0);
+        assert_eq!(ratio.to_clamped_absolute(2.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_open_ended_converter() {
+        let knob: LinearScale<f64> = LinearScale::new(0.0, 100.0);
+        let ratio: OpenEndedScale<f64> = OpenEndedScale::new(1.0, 4.0).unwrap();
+        let conv = (&knob, &ratio);
+
+        assert_eq!(conv.convert(100.0), f64::INFINITY);
+        assert_approx_eq!(conv.convert_back(f64::INFINITY), 100.0);
+        assert_approx_eq!(conv.add_external(-25.0, f64::INFINITY), 10.0);
+        assert_eq!(
+            conv.add_external_clamped(10.0, f64::INFINITY),
+            f64::INFINITY
+        );
+        assert_approx_eq!(conv.add_internal(6.0, 50.0), 75.0);
+    }
+
+    #[test]
+    fn test_open_ended_sentinel() {
+        let hold: OpenEndedScale<u32> = OpenEndedScale::new(0, 500).unwrap();
+
+        assert_eq!(hold.max(), u32::MAX);
+        assert_eq!(hold.to_absolute(1.0), u32::MAX);
+        assert_eq!(hold.to_relative(u32::MAX), 1.0);
+        assert_eq!(hold.to_absolute(0.5), 500);
+        assert_approx_eq!(hold.to_relative(1500), 0.75);
+    }
+
+    #[test]
+    fn test_open_ended_invalid_centre() {
+        let below: Result<OpenEndedScale<f64>, _> = OpenEndedScale::new(4.0, 1.0);
+        assert_eq!(below, Err(ScaleError::CentreOutOfRange));
+
+        let equal: Result<OpenEndedScale<f64>, _> = OpenEndedScale::new(1.0, 1.0);
+        assert_eq!(equal, Err(ScaleError::CentreOutOfRange));
+
+        let infinite: Result<OpenEndedScale<f64>, _> = OpenEndedScale::new(1.0, f64::INFINITY);
+        assert_eq!(infinite, Err(ScaleError::CentreOutOfRange));
+
+        let sentinel: Result<OpenEndedScale<u32>, _> = OpenEndedScale::new(0, u32::MAX);
+        assert_eq!(sentinel, Err(ScaleError::CentreOutOfRange));
+    }
+}
@@ -0,0 +1,203 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+const CONTINUITY_TOLERANCE: f64 = 1e-9;
+
+struct Segment<N> {
+    scale: Box<dyn Scale<N>>,
+    start: f64,
+    end: f64,
+}
+
+/// A scale made up of consecutive segments, each of which is an arbitrary scale owning a sub-range
+/// of the relative range.
+///
+/// Segments are provided in order, together with the relative value at which they end. The first segment
+/// starts at relative 0.0 and each following segment starts where the previous one ends. The maximum of each
+/// segment must match the minimum of the following segment, so the scale is continuous at the joints.
+/// Values outside of the scale's range are handled by the first or last segment respectively.
+pub struct PiecewiseScale<N> {
+    segments: Vec<Segment<N>>,
+}
+
+impl<N> PiecewiseScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a piecewise scale from segments and their relative end values. Fails if no segments are provided,
+    /// if the relative end values are not ascending or the last one is not 1.0, or if two adjacent segments
+    /// do not meet.
+    pub fn new(segments: Vec<(Box<dyn Scale<N>>, f64)>) -> Result<PiecewiseScale<N>, ScaleError> {
+        if segments.is_empty() {
+            return Err(ScaleError::NoValues);
+        }
+
+        let mut start = 0.0;
+        let mut checked: Vec<Segment<N>> = Vec::with_capacity(segments.len());
+        for (scale, end) in segments {
+            if end <= start || end > 1.0 {
+                return Err(ScaleError::InvalidSegmentBounds);
+            }
+            if let Some(previous) = checked.last() {
+                let previous_max = previous.scale.max().to_float();
+                let min = scale.min().to_float();
+                let tolerance = CONTINUITY_TOLERANCE * previous_max.abs().max(min.abs()).max(1.0);
+                if (previous_max - min).abs() > tolerance {
+                    return Err(ScaleError::Discontinuity {
+                        index: checked.len() - 1,
+                    });
+                }
+            }
+            checked.push(Segment { scale, start, end });
+            start = end;
+        }
+
+        if start != 1.0 {
+            return Err(ScaleError::InvalidSegmentBounds);
+        }
+
+        Ok(PiecewiseScale { segments: checked })
+    }
+
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// The scale of the segment with the provided index.
+    pub fn segment(&self, index: usize) -> Option<&dyn Scale<N>> {
+        self.segments.get(index).map(|s| s.scale.as_ref())
+    }
+
+    /// The relative range covered by the segment with the provided index.
+    pub fn segment_bounds(&self, index: usize) -> Option<(f64, f64)> {
+        self.segments.get(index).map(|s| (s.start, s.end))
+    }
+
+    /// The index of the segment an absolute value falls into.
+    pub fn segment_index(&self, absolute: N) -> usize {
+        let last = self.segments.len() - 1;
+        self.segments[..last]
+            .iter()
+            .position(|s| s.scale.to_relative(absolute.clone()) < 1.0)
+            .unwrap_or(last)
+    }
+}
+
+impl<N> Scale<N> for PiecewiseScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let segment = &self.segments[self.segment_index(absolute.clone())];
+        let relative = segment.scale.to_relative(absolute);
+        segment.start + relative * (segment.end - segment.start)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let last = self.segments.len() - 1;
+        let segment = self.segments[..last]
+            .iter()
+            .find(|s| relative < s.end)
+            .unwrap_or(&self.segments[last]);
+        let relative = (relative - segment.start) / (segment.end - segment.start);
+        segment.scale.to_absolute(relative)
+    }
+
+    fn max(&self) -> N {
+        self.segments[self.segments.len() - 1].scale.max()
+    }
+
+    fn min(&self) -> N {
+        self.segments[0].scale.min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    fn fader() -> PiecewiseScale<f64> {
+        PiecewiseScale::new(vec![
+            (Box::new(LinearScale::new(-60.0, -20.0)), 0.25),
+            (Box::new(LinearScale::new(-20.0, 10.0)), 1.0),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_piecewise_linear() {
+        let scale = fader();
+
+        assert_approx_eq!(scale.to_relative(-60.0), 0.0);
+        assert_approx_eq!(scale.to_relative(-40.0), 0.125);
+        assert_approx_eq!(scale.to_relative(-20.0), 0.25);
+        assert_approx_eq!(scale.to_relative(-5.0), 0.625);
+        assert_approx_eq!(scale.to_relative(10.0), 1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), -60.0);
+        assert_approx_eq!(scale.to_absolute(0.125), -40.0);
+        assert_approx_eq!(scale.to_absolute(0.25), -20.0);
+        assert_approx_eq!(scale.to_absolute(0.625), -5.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 10.0);
+
+        assert_eq!(scale.min(), -60.0);
+        assert_eq!(scale.max(), 10.0);
+        assert_eq!(scale.segment_count(), 2);
+        assert_eq!(scale.segment_bounds(1), Some((0.25, 1.0)));
+        assert_eq!(scale.segment_index(-30.0), 0);
+        assert_eq!(scale.segment_index(0.0), 1);
+    }
+
+    #[test]
+    fn test_piecewise_mixed() {
+        let scale: PiecewiseScale<f64> = PiecewiseScale::new(vec![
+            (Box::new(LinearScale::new(0.0, 20.0)), 0.2),
+            (Box::new(LogarithmicScale::new(20.0, 20_000.0)), 1.0),
+        ])
+        .unwrap();
+
+        assert_approx_eq!(scale.to_relative(10.0), 0.1);
+        assert_approx_eq!(scale.to_relative(200.0), 0.2 + 0.8 / 3.0);
+        assert_approx_eq!(scale.to_absolute(0.2 + 1.6 / 3.0), 2_000.0);
+        assert_approx_eq!(scale.to_absolute(scale.to_relative(5.0)), 5.0);
+
+        let slider: LinearScale<f64> = LinearScale::new(0.0, 100.0);
+        assert_approx_eq!((&slider, &scale).convert(20.0), 20.0);
+    }
+
+    #[test]
+    fn test_piecewise_out_of_range() {
+        let scale = fader();
+
+        assert_approx_eq!(scale.to_relative(-100.0), -0.25);
+        assert_approx_eq!(scale.to_relative(40.0), 1.75);
+        assert_approx_eq!(scale.to_absolute(-0.25), -100.0);
+        assert_approx_eq!(scale.to_clamped_relative(40.0), 1.0);
+    }
+
+    #[test]
+    fn test_piecewise_invalid() {
+        let empty: Result<PiecewiseScale<f64>, _> = PiecewiseScale::new(vec![]);
+        assert_eq!(empty.err(), Some(ScaleError::NoValues));
+
+        let gap: Result<PiecewiseScale<f64>, _> = PiecewiseScale::new(vec![
+            (Box::new(LinearScale::new(0.0, 1.0)), 0.5),
+            (Box::new(LinearScale::new(1.0, 2.0)), 0.75),
+            (Box::new(LinearScale::new(3.0, 4.0)), 1.0),
+        ]);
+        assert_eq!(gap.err(), Some(ScaleError::Discontinuity { index: 1 }));
+
+        let unsorted: Result<PiecewiseScale<f64>, _> = PiecewiseScale::new(vec![
+            (Box::new(LinearScale::new(0.0, 1.0)), 0.5),
+            (Box::new(LinearScale::new(1.0, 2.0)), 0.5),
+        ]);
+        assert_eq!(unsorted.err(), Some(ScaleError::InvalidSegmentBounds));
+
+        let short: Result<PiecewiseScale<f64>, _> =
+            PiecewiseScale::new(vec![(Box::new(LinearScale::new(0.0, 1.0)), 0.9)]);
+        assert_eq!(short.err(), Some(ScaleError::InvalidSegmentBounds));
+    }
+}
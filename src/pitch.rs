@@ -0,0 +1,313 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// The MIDI note number of the reference pitch A4.
+pub const REFERENCE_NOTE: f64 = 69.0;
+
+/// The standard concert pitch of A4 in Hz.
+pub const CONCERT_PITCH: f64 = 440.0;
+
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// A scale for frequencies in Hz that is linear in pitch, i.e. in equal tempered MIDI note numbers.
+///
+/// The frequency of A4 (MIDI note 69) is configurable and defaults to 440 Hz. Besides converting between
+/// frequencies and relative values the scale exposes views of the same range in note numbers and cents,
+/// so for example a keyboard widget using note numbers and a frequency knob can be linked with a converter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PitchScale<N> {
+    min: N,
+    max: N,
+    reference: f64,
+    note_delegate: LinearScale<f64>,
+}
+
+impl<N> PitchScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a pitch scale between two frequencies, tuned to A4 = 440 Hz.
+    pub fn new(min: N, max: N) -> PitchScale<N> {
+        PitchScale::with_reference(min, max, CONCERT_PITCH)
+    }
+
+    /// Create a pitch scale between two frequencies, tuned to the provided frequency of A4.
+    pub fn with_reference(min: N, max: N, reference: f64) -> PitchScale<N> {
+        let min_note = note_number(min.clone().to_float(), reference);
+        let max_note = note_number(max.clone().to_float(), reference);
+
+        PitchScale {
+            min,
+            max,
+            reference,
+            note_delegate: LinearScale::new(min_note, max_note),
+        }
+    }
+
+    /// Create a pitch scale between two (possibly fractional) MIDI note numbers, tuned to the provided frequency of A4.
+    pub fn from_notes(min_note: f64, max_note: f64, reference: f64) -> PitchScale<N> {
+        PitchScale {
+            min: N::from_float(frequency(min_note, reference)),
+            max: N::from_float(frequency(max_note, reference)),
+            reference,
+            note_delegate: LinearScale::new(min_note, max_note),
+        }
+    }
+
+    /// The frequency of A4 in Hz this scale is tuned to.
+    pub fn reference(&self) -> f64 {
+        self.reference
+    }
+
+    /// Convert a frequency into a (possibly fractional) MIDI note number.
+    pub fn note_number(&self, frequency: N) -> f64 {
+        note_number(frequency.to_float(), self.reference)
+    }
+
+    /// Convert a (possibly fractional) MIDI note number into a frequency.
+    pub fn frequency(&self, note_number: f64) -> N {
+        N::from_float(frequency(note_number, self.reference))
+    }
+
+    /// Convert a frequency into its distance from the reference pitch A4 in cents.
+    pub fn cents(&self, frequency: N) -> f64 {
+        (self.note_number(frequency) - REFERENCE_NOTE) * 100.0
+    }
+
+    /// Convert a distance from the reference pitch A4 in cents into a frequency.
+    pub fn from_cents(&self, cents: f64) -> N {
+        self.frequency(REFERENCE_NOTE + cents / 100.0)
+    }
+
+    /// A linear scale of MIDI note numbers that covers the same range as this scale.
+    pub fn note_scale(&self) -> LinearScale<f64> {
+        self.note_delegate.clone()
+    }
+
+    /// A linear scale of cents relative to the reference pitch A4 that covers the same range as this scale.
+    pub fn cents_scale(&self) -> LinearScale<f64> {
+        LinearScale::new(
+            (self.note_delegate.min() - REFERENCE_NOTE) * 100.0,
+            (self.note_delegate.max() - REFERENCE_NOTE) * 100.0,
+        )
+    }
+
+    /// Format a frequency as the name of the nearest note and its deviation in cents, e.g. "A4 +12c".
+    pub fn format_frequency(&self, frequency: N) -> String {
+        format_note(self.note_number(frequency))
+    }
+
+    /// Parse a note name like "C#4" or "A4 +12c" into a frequency.
+    pub fn parse_frequency(&self, name: &str) -> Result<N, ScaleError> {
+        parse_note(name).map(|note| self.frequency(note))
+    }
+}
+
+impl<N> Scale<N> for PitchScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.note_delegate.to_relative(self.note_number(absolute))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.frequency(self.note_delegate.to_absolute(relative))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// Format a (possibly fractional) MIDI note number as the name of the nearest note, using sharps and
+/// scientific pitch notation (middle C being C4), followed by its deviation in cents if there is any,
+/// e.g. "C#4" or "A4 +12c".
+pub fn format_note(note_number: f64) -> String {
+    let nearest = note_number.round();
+    let cents = ((note_number - nearest) * 100.0).round();
+    let name = NOTE_NAMES[nearest.rem_euclid(12.0) as usize];
+    let octave = (nearest / 12.0).floor() as i64 - 1;
+
+    if cents == 0.0 {
+        format!("{}{}", name, octave)
+    } else {
+        format!("{}{} {:+}c", name, octave, cents as i64)
+    }
+}
+
+/// Parse a note name like "C#4", "Db4", "C-1" or "A4 +12c" into a (possibly fractional) MIDI note number.
+pub fn parse_note(name: &str) -> Result<f64, ScaleError> {
+    let invalid = || ScaleError::InvalidNoteName(name.to_owned());
+    let trimmed = name.trim();
+    let mut chars = trimmed.char_indices().peekable();
+
+    let semitone = match chars.next().map(|(_, c)| c.to_ascii_uppercase()) {
+        Some('C') => 0,
+        Some('D') => 2,
+        Some('E') => 4,
+        Some('F') => 5,
+        Some('G') => 7,
+        Some('A') => 9,
+        Some('B') => 11,
+        _ => return Err(invalid()),
+    };
+
+    let mut accidental = 0;
+    while let Some((_, c)) = chars.peek() {
+        match c {
+            '#' | '♯' => accidental += 1,
+            'b' | '♭' => accidental -= 1,
+            _ => break,
+        }
+        chars.next();
+    }
+
+    let rest = match chars.peek() {
+        Some((i, _)) => &trimmed[*i..],
+        None => return Err(invalid()),
+    };
+    let sign_len = if rest.starts_with('-') { 1 } else { 0 };
+    let octave_len = rest[sign_len..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map(|i| i + sign_len)
+        .unwrap_or_else(|| rest.len());
+    let octave: i64 = rest[..octave_len].parse().map_err(|_| invalid())?;
+
+    let cents = rest[octave_len..].trim();
+    let cents = if cents.is_empty() {
+        0.0
+    } else {
+        match cents.strip_suffix('c') {
+            Some(cents) => cents.trim().parse::<f64>().map_err(|_| invalid())?,
+            None => return Err(invalid()),
+        }
+    };
+
+    let note = octave
+        .checked_add(1)
+        .and_then(|o| o.checked_mul(12))
+        .and_then(|n| n.checked_add(semitone + accidental))
+        .ok_or_else(invalid)?;
+
+    Ok(note as f64 + cents / 100.0)
+}
+
+fn note_number(frequency: f64, reference: f64) -> f64 {
+    REFERENCE_NOTE + 12.0 * (frequency / reference).log2()
+}
+
+fn frequency(note_number: f64, reference: f64) -> f64 {
+    reference * ((note_number - REFERENCE_NOTE) / 12.0).exp2()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_pitch() {
+        let scale: PitchScale<f64> = PitchScale::new(27.5, 7040.0);
+
+        assert_approx_eq!(scale.to_relative(27.5), 0.0);
+        assert_approx_eq!(scale.to_relative(440.0), 0.5);
+        assert_approx_eq!(scale.to_relative(7040.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.25), 110.0);
+        assert_approx_eq!(scale.to_absolute(0.75), 1760.0);
+
+        assert_approx_eq!(scale.note_number(440.0), 69.0);
+        assert_approx_eq!(scale.note_number(261.625_565), 60.0);
+        assert_approx_eq!(scale.frequency(60.0), 261.625_565);
+        assert_approx_eq!(scale.frequency(0.0), 8.175_799);
+        assert_approx_eq!(scale.cents(880.0), 1200.0);
+        assert_approx_eq!(scale.from_cents(-1200.0), 220.0);
+    }
+
+    #[test]
+    fn test_pitch_reference() {
+        let scale: PitchScale<f64> = PitchScale::with_reference(20.0, 20_000.0, 432.0);
+
+        assert_approx_eq!(scale.note_number(432.0), 69.0);
+        assert_approx_eq!(scale.frequency(81.0), 864.0);
+        assert_approx_eq!(scale.reference(), 432.0);
+
+        let scale: PitchScale<f64> = PitchScale::from_notes(21.0, 108.0, 415.0);
+        assert_approx_eq!(scale.min(), 415.0 / 16.0);
+        assert_approx_eq!(scale.to_absolute(48.0 / 87.0), 415.0);
+    }
+
+    #[test]
+    fn test_pitch_views() {
+        let scale: PitchScale<f64> = PitchScale::from_notes(21.0, 108.0, 440.0);
+
+        let notes = scale.note_scale();
+        assert_approx_eq!(notes.min(), 21.0);
+        assert_approx_eq!(notes.max(), 108.0);
+
+        let cents = scale.cents_scale();
+        assert_approx_eq!(cents.min(), -4800.0);
+        assert_approx_eq!(cents.max(), 3900.0);
+
+        let keyboard: LinearScale<u8> = LinearScale::new(21, 108);
+        assert_approx_eq!((&keyboard, &scale).convert(69), 440.0);
+        assert_approx_eq!((&keyboard, &scale).convert(60), 261.625_565);
+        assert_eq!((&keyboard, &scale).convert_back(880.0), 81);
+
+        let knob: LinearScale<f64> = LinearScale::new(0.0, 1.0);
+        let relative = (&scale, &knob).convert(440.0);
+        assert_approx_eq!((&knob, &notes).convert(relative), 69.0);
+    }
+
+    #[test]
+    fn test_format_note() {
+        assert_eq!(format_note(69.0), "A4");
+        assert_eq!(format_note(60.0), "C4");
+        assert_eq!(format_note(61.0), "C#4");
+        assert_eq!(format_note(0.0), "C-1");
+        assert_eq!(format_note(69.12), "A4 +12c");
+        assert_eq!(format_note(68.7), "A4 -30c");
+
+        let scale: PitchScale<f64> = PitchScale::new(20.0, 20_000.0);
+        assert_eq!(scale.format_frequency(440.0), "A4");
+        assert_eq!(
+            scale.format_frequency(440.0 * 2f64.powf(12.0 / 1200.0)),
+            "A4 +12c"
+        );
+    }
+
+    #[test]
+    fn test_parse_note() {
+        assert_approx_eq!(parse_note("A4").unwrap(), 69.0);
+        assert_approx_eq!(parse_note("C4").unwrap(), 60.0);
+        assert_approx_eq!(parse_note("C#4").unwrap(), 61.0);
+        assert_approx_eq!(parse_note("Db4").unwrap(), 61.0);
+        assert_approx_eq!(parse_note("bb3").unwrap(), 58.0);
+        assert_approx_eq!(parse_note("C-1").unwrap(), 0.0);
+        assert_approx_eq!(parse_note(" A4 +12c ").unwrap(), 69.12);
+        assert_approx_eq!(parse_note("A4-30c").unwrap(), 68.7);
+
+        assert_eq!(
+            parse_note("H4"),
+            Err(ScaleError::InvalidNoteName("H4".to_owned()))
+        );
+        assert!(parse_note("").is_err());
+        assert!(parse_note("C#").is_err());
+        assert!(parse_note("A4 +12").is_err());
+        assert!(parse_note("C9223372036854775807").is_err());
+        assert!(parse_note("Cb-9223372036854775808").is_err());
+
+        let scale: PitchScale<f64> = PitchScale::new(20.0, 20_000.0);
+        assert_approx_eq!(scale.parse_frequency("A5").unwrap(), 880.0);
+        assert_approx_eq!(scale.parse_frequency("C#4").unwrap(), 277.182_631);
+    }
+}
@@ -0,0 +1,209 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A scale that maps a linear range to a relative value by raising it to the power of a skew factor,
+/// comparable to JUCE's `NormalisableRange`.
+///
+/// A skew factor below 1.0 spreads out the lower end of the range, a skew factor above 1.0 the upper end.
+/// In symmetric mode the skew is applied to both halves of the range away from a centre value,
+/// which always lands at relative 0.5.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerScale<N> {
+    min: N,
+    max: N,
+    min_f64: f64,
+    max_f64: f64,
+    skew: f64,
+    centre: Option<f64>,
+}
+
+impl<N> PowerScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a scale with the provided skew factor. Fails if the skew is not a finite value greater than zero.
+    pub fn new(min: N, max: N, skew: f64) -> Result<PowerScale<N>, ScaleError> {
+        if !skew.is_finite() || skew <= 0.0 {
+            return Err(ScaleError::InvalidSkew);
+        }
+
+        Ok(PowerScale {
+            min_f64: min.clone().to_float(),
+            max_f64: max.clone().to_float(),
+            min,
+            max,
+            skew,
+            centre: None,
+        })
+    }
+
+    /// Create a scale that applies the skew symmetrically to both sides of the provided centre value.
+    pub fn symmetric(min: N, max: N, centre: N, skew: f64) -> Result<PowerScale<N>, ScaleError> {
+        let mut scale = PowerScale::new(min, max, skew)?;
+        let centre = centre.to_float();
+        if centre <= scale.min_f64 || centre >= scale.max_f64 {
+            return Err(ScaleError::CentreOutOfRange);
+        }
+        scale.centre = Some(centre);
+        Ok(scale)
+    }
+
+    /// Create a scale with a skew factor chosen so that the provided centre value lands at relative 0.5.
+    pub fn with_centre(min: N, max: N, centre: N) -> Result<PowerScale<N>, ScaleError> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+        let centre = centre.to_float();
+        if centre <= min_f64 || centre >= max_f64 {
+            return Err(ScaleError::CentreOutOfRange);
+        }
+        let skew = 0.5f64.ln() / ((centre - min_f64) / (max_f64 - min_f64)).ln();
+        PowerScale::new(min, max, skew)
+    }
+
+    pub fn skew(&self) -> f64 {
+        self.skew
+    }
+
+    /// The centre value of a symmetric scale, `None` if the scale is not symmetric.
+    pub fn centre(&self) -> Option<N> {
+        self.centre.map(N::from_float)
+    }
+
+    fn skewed(&self, proportion: f64) -> f64 {
+        proportion.signum() * proportion.abs().powf(self.skew)
+    }
+
+    fn unskewed(&self, proportion: f64) -> f64 {
+        proportion.signum() * proportion.abs().powf(1.0 / self.skew)
+    }
+}
+
+impl<N> Scale<N> for PowerScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let absolute = absolute.to_float();
+
+        match self.centre {
+            None => self.skewed((absolute - self.min_f64) / (self.max_f64 - self.min_f64)),
+            Some(centre) if absolute >= centre => {
+                0.5 + 0.5 * self.skewed((absolute - centre) / (self.max_f64 - centre))
+            }
+            Some(centre) => 0.5 - 0.5 * self.skewed((centre - absolute) / (centre - self.min_f64)),
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let abs = match self.centre {
+            None => self.min_f64 + (self.max_f64 - self.min_f64) * self.unskewed(relative),
+            Some(centre) if relative >= 0.5 => {
+                centre + (self.max_f64 - centre) * self.unskewed(2.0 * relative - 1.0)
+            }
+            Some(centre) => centre - (centre - self.min_f64) * self.unskewed(1.0 - 2.0 * relative),
+        };
+        N::from_float(abs)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_power() {
+        let scale: PowerScale<f64> = PowerScale::new(0.0, 100.0, 0.5).unwrap();
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.to_relative(25.0), 0.5);
+        assert_approx_eq!(scale.to_relative(100.0), 1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 25.0);
+        assert_approx_eq!(scale.to_absolute(0.1), 1.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 100.0);
+    }
+
+    #[test]
+    fn test_power_out_of_range() {
+        let scale: PowerScale<f64> = PowerScale::new(0.0, 100.0, 2.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(-50.0), -0.25);
+        assert_approx_eq!(scale.to_absolute(-0.25), -50.0);
+        assert_approx_eq!(scale.to_relative(200.0), 4.0);
+        assert_approx_eq!(scale.to_absolute(4.0), 200.0);
+    }
+
+    #[test]
+    fn test_power_symmetric() {
+        let scale: PowerScale<f64> = PowerScale::symmetric(-1.0, 1.0, 0.0, 2.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(-1.0), 0.0);
+        assert_approx_eq!(scale.to_relative(-0.5), 0.375);
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(0.5), 0.625);
+        assert_approx_eq!(scale.to_relative(1.0), 1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.375), -0.5);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.625), 0.5);
+
+        let asymmetric: PowerScale<f64> = PowerScale::symmetric(-60.0, 12.0, 0.0, 0.5).unwrap();
+        assert_approx_eq!(asymmetric.to_relative(0.0), 0.5);
+        assert_approx_eq!(asymmetric.to_relative(-15.0), 0.25);
+        assert_approx_eq!(asymmetric.to_relative(3.0), 0.75);
+        assert_approx_eq!(asymmetric.to_absolute(0.25), -15.0);
+        assert_eq!(asymmetric.centre(), Some(0.0));
+    }
+
+    #[test]
+    fn test_power_with_centre() {
+        let scale: PowerScale<f64> = PowerScale::with_centre(20.0, 20_000.0, 1_000.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(1_000.0), 0.5);
+        assert_approx_eq!(scale.to_absolute(0.5), 1_000.0);
+        assert_approx_eq!(scale.to_absolute(0.0), 20.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 20_000.0);
+
+        let slider: LinearScale<f64> = LinearScale::new(0.0, 100.0);
+        assert_approx_eq!((&slider, &scale).convert(50.0), 1_000.0);
+        assert_approx_eq!((&slider, &scale).convert_back(1_000.0), 50.0);
+    }
+
+    #[test]
+    fn test_power_invalid_centre() {
+        let err = PowerScale::with_centre(0.0, 1.0, 1.0);
+        assert_eq!(err, Err(ScaleError::CentreOutOfRange));
+
+        let err = PowerScale::symmetric(0.0, 1.0, -0.5, 2.0);
+        assert_eq!(err, Err(ScaleError::CentreOutOfRange));
+    }
+
+    #[test]
+    fn test_power_invalid_skew() {
+        assert_eq!(PowerScale::new(0.0, 1.0, 0.0), Err(ScaleError::InvalidSkew));
+        assert_eq!(
+            PowerScale::new(0.0, 1.0, -2.0),
+            Err(ScaleError::InvalidSkew)
+        );
+        assert_eq!(
+            PowerScale::new(0.0, 1.0, f64::NAN),
+            Err(ScaleError::InvalidSkew)
+        );
+        assert_eq!(
+            PowerScale::symmetric(0.0, 1.0, 0.5, 0.0),
+            Err(ScaleError::InvalidSkew)
+        );
+    }
+}
@@ -1,6 +1,30 @@
+pub use crate::band::*;
 pub use crate::broken::*;
+pub use crate::bucket::*;
+pub use crate::companding::*;
 pub use crate::convert::*;
 pub use crate::converter::*;
+pub use crate::curve::*;
+pub use crate::cyclic::*;
+pub use crate::decibel::*;
+pub use crate::diverging::*;
+pub use crate::enumerated::*;
+pub use crate::error::*;
+pub use crate::function::*;
+pub use crate::inverted::*;
 pub use crate::linear::*;
 pub use crate::logarithmic::*;
+pub use crate::morph::*;
+pub use crate::open_ended::*;
+pub use crate::piecewise::*;
+pub use crate::pitch::*;
+pub use crate::power::*;
+pub use crate::probability::*;
+pub use crate::psychoacoustic::*;
+pub use crate::reciprocal::*;
+pub use crate::sigmoid::*;
+pub use crate::stepped::*;
+pub use crate::symlog::*;
+pub use crate::transfer::*;
+pub use crate::viewport::*;
 pub use crate::*;
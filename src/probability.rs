@@ -0,0 +1,327 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// A scale for probabilities that is linear in their log-odds, `ln(p / (1 - p))`.
+///
+/// The probabilities 0.0 and 1.0 are asymptotic: they map to relative -inf and +inf respectively, so a
+/// logit scale must be constructed with a minimum and maximum strictly between 0.0 and 1.0. Clamping via
+/// [`to_clamped_relative`](Scale::to_clamped_relative) limits the input to that range and therefore always
+/// produces finite relative values between 0.0 and 1.0, which makes it safe to use for plot axes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogitScale<N> {
+    min: N,
+    max: N,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> LogitScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a new logit scale. Fails if minimum or maximum do not lie strictly between 0.0 and 1.0.
+    pub fn new(min: N, max: N) -> Result<LogitScale<N>, ScaleError> {
+        let (min_f64, max_f64) = probability_range(&min, &max)?;
+        let linear_delegate = LinearScale::new(logit(min_f64), logit(max_f64));
+
+        Ok(LogitScale {
+            min,
+            max,
+            linear_delegate,
+        })
+    }
+}
+
+impl<N> Scale<N> for LogitScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.linear_delegate.to_relative(logit(absolute.to_float()))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(logistic(self.linear_delegate.to_absolute(relative)))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// A scale for probabilities that is linear in the quantiles of the standard normal distribution,
+/// i.e. a normal probability (probit) axis.
+///
+/// Like with [`LogitScale`], the probabilities 0.0 and 1.0 map to relative -inf and +inf respectively,
+/// so the minimum and maximum must lie strictly between 0.0 and 1.0, and
+/// [`to_clamped_relative`](Scale::to_clamped_relative) always produces finite relative values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbitScale<N> {
+    min: N,
+    max: N,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> ProbitScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a new probit scale. Fails if minimum or maximum do not lie strictly between 0.0 and 1.0.
+    pub fn new(min: N, max: N) -> Result<ProbitScale<N>, ScaleError> {
+        let (min_f64, max_f64) = probability_range(&min, &max)?;
+        let linear_delegate = LinearScale::new(normal_quantile(min_f64), normal_quantile(max_f64));
+
+        Ok(ProbitScale {
+            min,
+            max,
+            linear_delegate,
+        })
+    }
+}
+
+impl<N> Scale<N> for ProbitScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.linear_delegate
+            .to_relative(normal_quantile(absolute.to_float()))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(normal_cdf(self.linear_delegate.to_absolute(relative)))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+fn probability_range<N>(min: &N, max: &N) -> Result<(f64, f64), ScaleError>
+where
+    N: ToFloat<f64> + Clone,
+{
+    let min = min.clone().to_float();
+    let max = max.clone().to_float();
+    let valid = |p: f64| p > 0.0 && p < 1.0;
+    if !valid(min) || !valid(max) {
+        return Err(ScaleError::ProbabilityOutOfRange);
+    }
+    if min == max {
+        return Err(ScaleError::InvalidRange);
+    }
+    Ok((min, max))
+}
+
+/// The log-odds of a probability, `ln(p / (1 - p))`.
+pub fn logit(p: f64) -> f64 {
+    p.ln() - (-p).ln_1p()
+}
+
+/// The logistic function, the inverse of [`logit`].
+pub fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// The cumulative distribution function of the standard normal distribution.
+pub fn normal_cdf(x: f64) -> f64 {
+    if x.abs() < 3.0 {
+        // Marsaglia's series: 0.5 + pdf(x) * (x + x^3 / 3 + x^5 / (3 * 5) + ...)
+        let x2 = x * x;
+        let mut sum = x;
+        let mut term = x;
+        let mut i = 1.0;
+        loop {
+            i += 2.0;
+            term *= x2 / i;
+            let next = sum + term;
+            if next == sum {
+                break;
+            }
+            sum = next;
+        }
+        0.5 + sum * normal_pdf(x)
+    } else if x > 0.0 {
+        1.0 - normal_upper_tail(x)
+    } else {
+        normal_upper_tail(-x)
+    }
+}
+
+/// The quantile function (inverse cumulative distribution function) of the standard normal distribution.
+pub fn normal_quantile(p: f64) -> f64 {
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        f64::NAN
+    } else if p == 0.0 {
+        f64::NEG_INFINITY
+    } else if p == 1.0 {
+        f64::INFINITY
+    } else if p > 0.5 {
+        -lower_normal_quantile(1.0 - p)
+    } else {
+        lower_normal_quantile(p)
+    }
+}
+
+fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Upper tail probability of the standard normal distribution for x >= 3, evaluated as continued fraction.
+fn normal_upper_tail(x: f64) -> f64 {
+    let mut fraction = x;
+    for k in (1..=100).rev() {
+        fraction = x + k as f64 / fraction;
+    }
+    normal_pdf(x) / fraction
+}
+
+/// Normal quantile for 0 < p <= 0.5, using Acklam's rational approximation refined by Halley's method.
+fn lower_normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+
+    let mut x = if p < 0.024_25 {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    };
+
+    for _ in 0..2 {
+        let e = normal_cdf(x) - p;
+        let u = e / normal_pdf(x);
+        x -= u / (1.0 + x * u / 2.0);
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_normal_distribution() {
+        assert_approx_eq!(normal_cdf(0.0), 0.5);
+        assert_approx_eq!(normal_cdf(1.959_963_984_540_054), 0.975, 1e-15);
+        assert_approx_eq!(normal_cdf(-3.5), 2.326_290_790_355_401e-4, 1e-15);
+        assert_approx_eq!(normal_cdf(5.0), 0.999_999_713_348_428_2, 1e-15);
+
+        assert_approx_eq!(normal_quantile(0.5), 0.0);
+        assert_approx_eq!(normal_quantile(0.975), 1.959_963_984_540_054, 1e-12);
+        assert_approx_eq!(normal_quantile(0.01), -2.326_347_874_040_841, 1e-12);
+        assert_approx_eq!(normal_quantile(1e-10), -6.361_340_902_404_056, 1e-9);
+
+        assert_eq!(normal_quantile(0.0), f64::NEG_INFINITY);
+        assert_eq!(normal_quantile(1.0), f64::INFINITY);
+        assert!(normal_quantile(1.5).is_nan());
+    }
+
+    #[test]
+    fn test_logit() {
+        let scale: LogitScale<f64> = LogitScale::new(0.01, 0.99).unwrap();
+
+        assert_approx_eq!(scale.to_relative(0.01), 0.0);
+        assert_approx_eq!(scale.to_relative(0.5), 0.5);
+        assert_approx_eq!(scale.to_relative(0.99), 1.0);
+        assert_approx_eq!(scale.to_relative(0.2), 0.349_155_799);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 0.01);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.5);
+        assert_approx_eq!(scale.to_absolute(0.349_155_799), 0.2);
+        assert_approx_eq!(scale.to_absolute(1.0), 0.99);
+    }
+
+    #[test]
+    fn test_probit() {
+        let scale: ProbitScale<f64> = ProbitScale::new(0.01, 0.99).unwrap();
+
+        assert_approx_eq!(scale.to_relative(0.01), 0.0);
+        assert_approx_eq!(scale.to_relative(0.5), 0.5);
+        assert_approx_eq!(scale.to_relative(0.99), 1.0);
+
+        for i in 1..100 {
+            let p = i as f64 / 100.0;
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(p)), p, 1e-12);
+        }
+        assert_approx_eq!(scale.to_absolute(scale.to_relative(1e-9)), 1e-9, 1e-18);
+    }
+
+    #[test]
+    fn test_probability_open_endpoints() {
+        let logit: LogitScale<f64> = LogitScale::new(0.001, 0.999).unwrap();
+        let probit: ProbitScale<f64> = ProbitScale::new(0.001, 0.999).unwrap();
+        let scales: [&dyn Scale<f64>; 2] = [&logit, &probit];
+
+        for scale in scales.iter() {
+            assert_eq!(scale.to_relative(0.0), f64::NEG_INFINITY);
+            assert_eq!(scale.to_relative(1.0), f64::INFINITY);
+            assert_approx_eq!(scale.to_absolute(f64::NEG_INFINITY), 0.0);
+            assert_approx_eq!(scale.to_absolute(f64::INFINITY), 1.0);
+
+            assert_approx_eq!(scale.to_clamped_relative(0.0), 0.0);
+            assert_approx_eq!(scale.to_clamped_relative(1.0), 1.0);
+            assert_approx_eq!(scale.to_clamped_absolute(-1.0), 0.001);
+            assert_approx_eq!(scale.to_clamped_absolute(2.0), 0.999);
+
+            let axis: LinearScale<f64> = LinearScale::new(0.0, 400.0);
+            assert_approx_eq!(axis.to_absolute(scale.to_relative(0.5)), 200.0);
+        }
+    }
+
+    #[test]
+    fn test_probability_invalid_range() {
+        let logit: Result<LogitScale<f64>, _> = LogitScale::new(0.0, 1.0);
+        assert_eq!(logit, Err(ScaleError::ProbabilityOutOfRange));
+        let logit: Result<LogitScale<f64>, _> = LogitScale::new(0.5, 0.5);
+        assert_eq!(logit, Err(ScaleError::InvalidRange));
+
+        let probit: Result<ProbitScale<f64>, _> = ProbitScale::new(0.01, 1.5);
+        assert_eq!(probit, Err(ScaleError::ProbabilityOutOfRange));
+        let probit: Result<ProbitScale<f64>, _> = ProbitScale::new(f64::NAN, 0.5);
+        assert_eq!(probit, Err(ScaleError::ProbabilityOutOfRange));
+    }
+}
@@ -0,0 +1,324 @@
+use super::convert::*;
+use super::linear::*;
+use super::*;
+
+/// The formula used to convert between frequencies in Hz and mels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MelFormula {
+    /// The formula used by HTK: `2595 * log10(1 + f / 700)`.
+    Htk,
+    /// The formula from Slaney's Auditory Toolbox, linear below 1 kHz and logarithmic above.
+    Slaney,
+}
+
+const SLANEY_HZ_PER_MEL: f64 = 200.0 / 3.0;
+const SLANEY_MIN_LOG_HZ: f64 = 1000.0;
+const SLANEY_MIN_LOG_MEL: f64 = SLANEY_MIN_LOG_HZ / SLANEY_HZ_PER_MEL;
+
+impl MelFormula {
+    /// Convert a frequency in Hz into mels.
+    pub fn to_mel(self, hz: f64) -> f64 {
+        match self {
+            MelFormula::Htk => 2595.0 * (hz / 700.0).ln_1p() / std::f64::consts::LN_10,
+            MelFormula::Slaney if hz < SLANEY_MIN_LOG_HZ => hz / SLANEY_HZ_PER_MEL,
+            MelFormula::Slaney => {
+                SLANEY_MIN_LOG_MEL + (hz / SLANEY_MIN_LOG_HZ).ln() / slaney_log_step()
+            }
+        }
+    }
+
+    /// Convert mels into a frequency in Hz.
+    pub fn to_hz(self, mel: f64) -> f64 {
+        match self {
+            MelFormula::Htk => 700.0 * (mel / 2595.0 * std::f64::consts::LN_10).exp_m1(),
+            MelFormula::Slaney if mel < SLANEY_MIN_LOG_MEL => mel * SLANEY_HZ_PER_MEL,
+            MelFormula::Slaney => {
+                SLANEY_MIN_LOG_HZ * (slaney_log_step() * (mel - SLANEY_MIN_LOG_MEL)).exp()
+            }
+        }
+    }
+}
+
+fn slaney_log_step() -> f64 {
+    6.4f64.ln() / 27.0
+}
+
+/// Convert a frequency in Hz into the Bark scale using Traunmüller's formula, including
+/// its corrections for the lowest and highest critical bands.
+pub fn hz_to_bark(hz: f64) -> f64 {
+    let bark = 26.81 * hz / (1960.0 + hz) - 0.53;
+    if bark < 2.0 {
+        bark + 0.15 * (2.0 - bark)
+    } else if bark > 20.1 {
+        bark + 0.22 * (bark - 20.1)
+    } else {
+        bark
+    }
+}
+
+/// Convert a value on the Bark scale into a frequency in Hz. This is the exact inverse of [`hz_to_bark`].
+pub fn bark_to_hz(bark: f64) -> f64 {
+    let bark = if bark < 2.0 {
+        (bark - 0.3) / 0.85
+    } else if bark > 20.1 {
+        (bark + 0.22 * 20.1) / 1.22
+    } else {
+        bark
+    };
+    1960.0 * (bark + 0.53) / (26.28 - bark)
+}
+
+/// Convert a frequency in Hz into the ERB-rate scale (number of ERBs below the frequency)
+/// as defined by Glasberg and Moore.
+pub fn hz_to_erb_rate(hz: f64) -> f64 {
+    21.4 * (0.004_37 * hz).ln_1p() / std::f64::consts::LN_10
+}
+
+/// Convert a value on the ERB-rate scale into a frequency in Hz. This is the exact inverse of [`hz_to_erb_rate`].
+pub fn erb_rate_to_hz(erb_rate: f64) -> f64 {
+    (erb_rate / 21.4 * std::f64::consts::LN_10).exp_m1() / 0.004_37
+}
+
+/// A frequency scale that is linear in mels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MelScale<N> {
+    min: N,
+    max: N,
+    formula: MelFormula,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> MelScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N, formula: MelFormula) -> MelScale<N> {
+        let linear_delegate = LinearScale::new(
+            formula.to_mel(min.clone().to_float()),
+            formula.to_mel(max.clone().to_float()),
+        );
+
+        MelScale {
+            min,
+            max,
+            formula,
+            linear_delegate,
+        }
+    }
+
+    pub fn htk(min: N, max: N) -> MelScale<N> {
+        MelScale::new(min, max, MelFormula::Htk)
+    }
+
+    pub fn slaney(min: N, max: N) -> MelScale<N> {
+        MelScale::new(min, max, MelFormula::Slaney)
+    }
+
+    pub fn formula(&self) -> MelFormula {
+        self.formula
+    }
+}
+
+impl<N> Scale<N> for MelScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let mel = self.formula.to_mel(absolute.to_float());
+        self.linear_delegate.to_relative(mel)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let mel = self.linear_delegate.to_absolute(relative);
+        N::from_float(self.formula.to_hz(mel))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// A frequency scale that is linear in Bark, see [`hz_to_bark`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarkScale<N> {
+    min: N,
+    max: N,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> BarkScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N) -> BarkScale<N> {
+        let linear_delegate = LinearScale::new(
+            hz_to_bark(min.clone().to_float()),
+            hz_to_bark(max.clone().to_float()),
+        );
+
+        BarkScale {
+            min,
+            max,
+            linear_delegate,
+        }
+    }
+}
+
+impl<N> Scale<N> for BarkScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.linear_delegate
+            .to_relative(hz_to_bark(absolute.to_float()))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(bark_to_hz(self.linear_delegate.to_absolute(relative)))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// A frequency scale that is linear in ERB-rate, see [`hz_to_erb_rate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErbScale<N> {
+    min: N,
+    max: N,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> ErbScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N) -> ErbScale<N> {
+        let linear_delegate = LinearScale::new(
+            hz_to_erb_rate(min.clone().to_float()),
+            hz_to_erb_rate(max.clone().to_float()),
+        );
+
+        ErbScale {
+            min,
+            max,
+            linear_delegate,
+        }
+    }
+}
+
+impl<N> Scale<N> for ErbScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.linear_delegate
+            .to_relative(hz_to_erb_rate(absolute.to_float()))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(erb_rate_to_hz(self.linear_delegate.to_absolute(relative)))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    const FREQUENCIES: [f64; 9] = [
+        0.0, 20.0, 100.0, 440.0, 999.0, 1000.0, 4000.0, 15_000.0, 20_000.0,
+    ];
+
+    #[test]
+    fn test_mel_formulas() {
+        assert_approx_eq!(MelFormula::Htk.to_mel(1000.0), 999.985_537, 1e-5);
+        assert_approx_eq!(MelFormula::Htk.to_mel(0.0), 0.0);
+        assert_approx_eq!(MelFormula::Slaney.to_mel(1000.0), 15.0);
+        assert_approx_eq!(MelFormula::Slaney.to_mel(440.0), 6.6);
+        assert_approx_eq!(MelFormula::Slaney.to_mel(6400.0), 42.0);
+
+        for hz in FREQUENCIES.iter() {
+            assert_approx_eq!(
+                MelFormula::Htk.to_hz(MelFormula::Htk.to_mel(*hz)),
+                *hz,
+                1e-9
+            );
+            assert_approx_eq!(
+                MelFormula::Slaney.to_hz(MelFormula::Slaney.to_mel(*hz)),
+                *hz,
+                1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn test_bark_and_erb() {
+        assert_approx_eq!(hz_to_bark(1000.0), 8.527_5, 1e-4);
+        assert_approx_eq!(hz_to_erb_rate(1000.0), 15.621_4, 1e-4);
+        assert_approx_eq!(hz_to_erb_rate(0.0), 0.0);
+
+        for hz in FREQUENCIES.iter() {
+            assert_approx_eq!(bark_to_hz(hz_to_bark(*hz)), *hz, 1e-9);
+            assert_approx_eq!(erb_rate_to_hz(hz_to_erb_rate(*hz)), *hz, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_psychoacoustic_scales() {
+        let mel: MelScale<f64> = MelScale::htk(0.0, 8000.0);
+        let slaney: MelScale<f64> = MelScale::slaney(0.0, 6400.0);
+        let bark: BarkScale<f64> = BarkScale::new(20.0, 20_000.0);
+        let erb: ErbScale<f64> = ErbScale::new(20.0, 20_000.0);
+
+        assert_approx_eq!(slaney.to_relative(1000.0), 15.0 / 42.0);
+        assert_approx_eq!(slaney.to_absolute(15.0 / 42.0), 1000.0);
+
+        let scales: [&dyn Scale<f64>; 4] = [&mel, &slaney, &bark, &erb];
+        for scale in scales.iter() {
+            assert_approx_eq!(scale.to_relative(scale.min()), 0.0);
+            assert_approx_eq!(scale.to_relative(scale.max()), 1.0);
+            assert_approx_eq!(scale.to_absolute(0.0), scale.min(), 1e-9);
+            assert_approx_eq!(scale.to_absolute(1.0), scale.max(), 1e-9);
+            for i in 0..=10 {
+                let relative = i as f64 / 10.0;
+                assert_approx_eq!(scale.to_relative(scale.to_absolute(relative)), relative);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mel_fft_display() {
+        let pixel_scale: LinearScale<f64> = LinearScale::new(0.0, 800.0);
+        let mel_scale: MelScale<f64> = MelScale::htk(0.0, 8000.0);
+        let conv = (&pixel_scale, &mel_scale);
+
+        let x = conv.convert_back(1000.0);
+        assert_approx_eq!(
+            x,
+            800.0 * 999.985_537 / MelFormula::Htk.to_mel(8000.0),
+            1e-3
+        );
+        assert_approx_eq!(conv.convert(x), 1000.0, 1e-9);
+        assert_approx_eq!(conv.convert_back(0.0), 0.0);
+        assert_approx_eq!(conv.convert_back(8000.0), 800.0);
+    }
+}
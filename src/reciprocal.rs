@@ -0,0 +1,119 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// A scale that is linear in the reciprocal `1 / x` of its absolute values, e.g. a scale for periods
+/// or delay times whose relative values are linear in frequency or rate.
+///
+/// The range must be strictly positive or strictly negative.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReciprocalScale<N> {
+    min: N,
+    max: N,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> ReciprocalScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a new reciprocal scale. Fails if the range contains or touches zero.
+    pub fn new(min: N, max: N) -> Result<ReciprocalScale<N>, ScaleError> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+
+        if !(min_f64 > 0.0 && max_f64 > 0.0 || min_f64 < 0.0 && max_f64 < 0.0) {
+            return Err(ScaleError::RangeContainsZero);
+        }
+
+        Ok(ReciprocalScale {
+            min,
+            max,
+            linear_delegate: LinearScale::new(-1.0 / min_f64, -1.0 / max_f64),
+        })
+    }
+}
+
+impl<N> Scale<N> for ReciprocalScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.linear_delegate.to_relative(-1.0 / absolute.to_float())
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(-1.0 / self.linear_delegate.to_absolute(relative))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_reciprocal() {
+        let scale: ReciprocalScale<f64> = ReciprocalScale::new(10.0, 1000.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(10.0), 0.0);
+        assert_approx_eq!(scale.to_relative(1000.0), 1.0);
+        assert_approx_eq!(scale.to_relative(20.0), 0.05 / 0.099);
+        assert_approx_eq!(scale.to_absolute(0.0), 10.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 1.0 / 0.0505);
+        assert_approx_eq!(scale.to_absolute(1.0), 1000.0);
+
+        for i in 0..=10 {
+            let relative = i as f64 / 10.0;
+            assert_approx_eq!(scale.to_relative(scale.to_absolute(relative)), relative);
+        }
+    }
+
+    #[test]
+    fn test_reciprocal_negative() {
+        let scale: ReciprocalScale<f64> = ReciprocalScale::new(-10.0, -1.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(-10.0), 0.0);
+        assert_approx_eq!(scale.to_relative(-2.0), 0.4 / 0.9);
+        assert_approx_eq!(scale.to_relative(-1.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.4 / 0.9), -2.0);
+    }
+
+    #[test]
+    fn test_reciprocal_period_to_frequency() {
+        let period: ReciprocalScale<f64> = ReciprocalScale::new(0.01, 1.0).unwrap();
+        let frequency: Inverted<LinearScale<f64>> = LinearScale::inverted(1.0, 100.0);
+        let conv = (&period, &frequency);
+
+        assert_approx_eq!(conv.convert(0.01), 100.0);
+        assert_approx_eq!(conv.convert(0.02), 50.0);
+        assert_approx_eq!(conv.convert(0.5), 2.0);
+        assert_approx_eq!(conv.convert_back(4.0), 0.25);
+    }
+
+    #[test]
+    fn test_reciprocal_invalid_range() {
+        assert_eq!(
+            ReciprocalScale::new(-1.0, 1.0),
+            Err(ScaleError::RangeContainsZero)
+        );
+        assert_eq!(
+            ReciprocalScale::new(0.0, 1.0),
+            Err(ScaleError::RangeContainsZero)
+        );
+        assert_eq!(
+            ReciprocalScale::new(-1.0, 0.0),
+            Err(ScaleError::RangeContainsZero)
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use super::convert::*;
+use super::*;
+
+/// The family of S-curves used by a [`SigmoidScale`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sigmoid {
+    /// The logistic function `1 / (1 + e^-x)`.
+    Logistic,
+    /// The hyperbolic tangent.
+    Tanh,
+}
+
+impl Sigmoid {
+    fn apply(self, x: f64) -> f64 {
+        match self {
+            Sigmoid::Logistic => 1.0 / (1.0 + (-x).exp()),
+            Sigmoid::Tanh => x.tanh(),
+        }
+    }
+
+    fn invert(self, y: f64) -> f64 {
+        match self {
+            Sigmoid::Logistic => y.ln() - (-y).ln_1p(),
+            Sigmoid::Tanh => y.atanh(),
+        }
+    }
+
+    fn derivative(self, x: f64) -> f64 {
+        let y = self.apply(x);
+        match self {
+            Sigmoid::Logistic => y * (1.0 - y),
+            Sigmoid::Tanh => 1.0 - y * y,
+        }
+    }
+}
+
+/// A scale that applies an S-curve between its minimum and maximum.
+///
+/// The curve is normalized so that relative 0.0 and 1.0 always map to exactly `min()` and `max()`.
+/// `steepness` controls how pronounced the S-curve is (a steepness close to 0.0 is practically linear),
+/// `centre` is the position of the inflection point as fraction of the range (0.5 being the middle).
+/// Outside of the range the curve is extended linearly, so the scale stays invertible everywhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SigmoidScale<N> {
+    min: N,
+    max: N,
+    min_f64: f64,
+    full_range: f64,
+    sigmoid: Sigmoid,
+    steepness: f64,
+    centre: f64,
+    resolution_at_ends: bool,
+    lower: f64,
+    upper: f64,
+}
+
+impl<N> SigmoidScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a scale that offers more resolution around the centre, i.e. the relative value changes
+    /// fastest around the centre of the absolute range.
+    pub fn new(min: N, max: N, sigmoid: Sigmoid, steepness: f64, centre: f64) -> SigmoidScale<N> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+
+        SigmoidScale {
+            min,
+            max,
+            min_f64,
+            full_range: max_f64 - min_f64,
+            sigmoid,
+            steepness,
+            centre,
+            resolution_at_ends: false,
+            lower: sigmoid.apply(-steepness * centre),
+            upper: sigmoid.apply(steepness * (1.0 - centre)),
+        }
+    }
+
+    /// Create a scale that offers more resolution towards the ends, i.e. the absolute value changes
+    /// fastest around the centre of the relative range.
+    pub fn ends(min: N, max: N, sigmoid: Sigmoid, steepness: f64, centre: f64) -> SigmoidScale<N> {
+        SigmoidScale {
+            resolution_at_ends: true,
+            ..SigmoidScale::new(min, max, sigmoid, steepness, centre)
+        }
+    }
+
+    pub fn sigmoid(&self) -> Sigmoid {
+        self.sigmoid
+    }
+
+    pub fn steepness(&self) -> f64 {
+        self.steepness
+    }
+
+    pub fn centre(&self) -> f64 {
+        self.centre
+    }
+
+    fn is_linear(&self) -> bool {
+        self.steepness.abs() < 1e-9
+    }
+
+    fn slope(&self, x: f64) -> f64 {
+        self.sigmoid.derivative(self.steepness * (x - self.centre)) * self.steepness
+            / (self.upper - self.lower)
+    }
+
+    fn curve(&self, x: f64) -> f64 {
+        if self.is_linear() || x == 0.0 || x == 1.0 {
+            x
+        } else if x < 0.0 {
+            x * self.slope(0.0)
+        } else if x > 1.0 {
+            1.0 + (x - 1.0) * self.slope(1.0)
+        } else {
+            let y = self.sigmoid.apply(self.steepness * (x - self.centre));
+            (y - self.lower) / (self.upper - self.lower)
+        }
+    }
+
+    fn inverse_curve(&self, y: f64) -> f64 {
+        if self.is_linear() || y == 0.0 || y == 1.0 {
+            y
+        } else if y < 0.0 {
+            y / self.slope(0.0)
+        } else if y > 1.0 {
+            1.0 + (y - 1.0) / self.slope(1.0)
+        } else {
+            let y = self.lower + y * (self.upper - self.lower);
+            self.centre + self.sigmoid.invert(y) / self.steepness
+        }
+    }
+}
+
+impl<N> Scale<N> for SigmoidScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let x = (absolute.to_float() - self.min_f64) / self.full_range;
+        if self.resolution_at_ends {
+            self.inverse_curve(x)
+        } else {
+            self.curve(x)
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        if relative == 0.0 {
+            return self.min();
+        }
+        if relative == 1.0 {
+            return self.max();
+        }
+
+        let x = if self.resolution_at_ends {
+            self.curve(relative)
+        } else {
+            self.inverse_curve(relative)
+        };
+        N::from_float(self.min_f64 + x * self.full_range)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_sigmoid() {
+        let scale: SigmoidScale<f64> = SigmoidScale::new(0.0, 100.0, Sigmoid::Logistic, 10.0, 0.5);
+
+        assert_eq!(scale.to_relative(0.0), 0.0);
+        assert_eq!(scale.to_relative(100.0), 1.0);
+        assert_eq!(scale.to_absolute(0.0), 0.0);
+        assert_eq!(scale.to_absolute(1.0), 100.0);
+
+        assert_approx_eq!(scale.to_relative(50.0), 0.5);
+        assert_approx_eq!(scale.to_absolute(0.5), 50.0);
+        assert_approx_eq!(scale.to_relative(25.0), 1.0 - scale.to_relative(75.0));
+        assert!(scale.to_relative(55.0) - scale.to_relative(45.0) > 0.2);
+        assert!(scale.to_relative(10.0) - scale.to_relative(0.0) < 0.05);
+
+        for i in 0..=20 {
+            let relative = i as f64 / 20.0;
+            assert_approx_eq!(scale.to_relative(scale.to_absolute(relative)), relative);
+        }
+    }
+
+    #[test]
+    fn test_sigmoid_ends() {
+        let scale: SigmoidScale<f64> = SigmoidScale::ends(-1.0, 1.0, Sigmoid::Tanh, 4.0, 0.5);
+
+        assert_eq!(scale.to_relative(-1.0), 0.0);
+        assert_eq!(scale.to_relative(1.0), 1.0);
+        assert_eq!(scale.to_absolute(0.0), -1.0);
+        assert_eq!(scale.to_absolute(1.0), 1.0);
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert!(scale.to_absolute(0.55) - scale.to_absolute(0.45) > 0.2);
+        assert!(scale.to_absolute(0.05) - scale.to_absolute(0.0) < 0.05);
+
+        for i in -10..=10 {
+            let absolute = i as f64 / 10.0;
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(absolute)), absolute);
+        }
+    }
+
+    #[test]
+    fn test_sigmoid_families() {
+        let logistic: SigmoidScale<f64> = SigmoidScale::new(0.0, 1.0, Sigmoid::Logistic, 8.0, 0.3);
+        let tanh: SigmoidScale<f64> = SigmoidScale::new(0.0, 1.0, Sigmoid::Tanh, 4.0, 0.3);
+
+        for i in 0..=10 {
+            let absolute = i as f64 / 10.0;
+            assert_approx_eq!(logistic.to_relative(absolute), tanh.to_relative(absolute));
+        }
+
+        assert!(logistic.to_relative(0.35) - logistic.to_relative(0.25) > 0.1);
+        assert!(logistic.to_relative(0.65) - logistic.to_relative(0.55) < 0.1);
+    }
+
+    #[test]
+    fn test_sigmoid_linear_and_out_of_range() {
+        let linear: SigmoidScale<f64> = SigmoidScale::new(0.0, 10.0, Sigmoid::Logistic, 0.0, 0.5);
+        assert_approx_eq!(linear.to_relative(2.5), 0.25);
+        assert_approx_eq!(linear.to_absolute(0.75), 7.5);
+
+        let scale: SigmoidScale<f64> = SigmoidScale::new(0.0, 10.0, Sigmoid::Tanh, 3.0, 0.5);
+        assert!(scale.to_relative(-1.0) < 0.0);
+        assert!(scale.to_relative(11.0) > 1.0);
+        assert_approx_eq!(scale.to_absolute(scale.to_relative(-1.0)), -1.0);
+        assert_approx_eq!(scale.to_absolute(scale.to_relative(11.0)), 11.0);
+        assert_approx_eq!(scale.to_relative(-1e-9), 0.0);
+        assert_approx_eq!(scale.to_clamped_relative(-5.0), 0.0);
+    }
+}
@@ -0,0 +1,239 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Stepping {
+    Relative {
+        step_count: usize,
+    },
+    Absolute {
+        origin: f64,
+        interval: f64,
+        step_count: usize,
+    },
+}
+
+/// A wrapper around another scale that only produces a fixed number of discrete values.
+///
+/// A stepped scale with a step count of `n` has `n + 1` discrete values, indexed from `0` to `n`
+/// (this matches the `stepCount` semantics of VST3). The steps are either evenly spaced in relative space
+/// ([`new`](SteppedScale::new)) or evenly spaced in absolute space ([`with_interval`](SteppedScale::with_interval)),
+/// in both cases following the curve of the wrapped scale.
+///
+/// The following convention applies:
+/// * [`to_relative`](Scale::to_relative) snaps to the nearest step and returns that step's exact relative value.
+///   With relative spacing this is `index / n`, so the first and last step are exactly 0.0 and 1.0.
+/// * [`to_absolute`](Scale::to_absolute) returns the exact absolute value of a step. With relative spacing the
+///   relative range is split into `n + 1` bands of equal width, step `i` being selected for relative values
+///   between `i / (n + 1)` and `(i + 1) / (n + 1)`. With absolute spacing the nearest step is selected.
+/// * Step indices never leave `0..=n`, so out of range values snap to the first or last step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteppedScale<N, S> {
+    delegate: S,
+    stepping: Stepping,
+    _number: PhantomData<N>,
+}
+
+impl<N, S> SteppedScale<N, S>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    /// Create a stepped scale with `step_count` steps that are evenly spaced in relative space.
+    pub fn new(delegate: S, step_count: usize) -> SteppedScale<N, S> {
+        SteppedScale {
+            delegate,
+            stepping: Stepping::Relative { step_count },
+            _number: PhantomData,
+        }
+    }
+
+    /// Create a stepped scale whose steps are `interval` apart in absolute space, starting at the minimum of the delegate.
+    /// Fails if the interval is not a finite value greater than zero.
+    pub fn with_interval(delegate: S, interval: N) -> Result<SteppedScale<N, S>, ScaleError> {
+        let origin = delegate.min().to_float();
+        let interval = interval.to_float();
+        if !interval.is_finite() || interval <= 0.0 {
+            return Err(ScaleError::InvalidInterval);
+        }
+        let range = delegate.max().to_float() - origin;
+        let step_count = (range / interval + 1e-9).floor().max(0.0) as usize;
+
+        Ok(SteppedScale {
+            delegate,
+            stepping: Stepping::Absolute {
+                origin,
+                interval,
+                step_count,
+            },
+            _number: PhantomData,
+        })
+    }
+
+    /// The number of steps. The scale has one more discrete value than it has steps.
+    pub fn step_count(&self) -> usize {
+        match self.stepping {
+            Stepping::Relative { step_count } => step_count,
+            Stepping::Absolute { step_count, .. } => step_count,
+        }
+    }
+
+    /// The index of the step nearest to an absolute value.
+    pub fn step_index(&self, absolute: N) -> usize {
+        let index = match self.stepping {
+            Stepping::Relative { step_count } => {
+                self.delegate.to_relative(absolute) * step_count as f64
+            }
+            Stepping::Absolute {
+                origin, interval, ..
+            } => (absolute.to_float() - origin) / interval,
+        };
+        self.clamp_index(index.round())
+    }
+
+    /// The index of the step a relative value selects.
+    pub fn step_index_of_relative(&self, relative: f64) -> usize {
+        match self.stepping {
+            Stepping::Relative { step_count } => {
+                self.clamp_index((relative * (step_count + 1) as f64).floor())
+            }
+            Stepping::Absolute { .. } => self.step_index(self.delegate.to_absolute(relative)),
+        }
+    }
+
+    /// The exact relative value of a step.
+    pub fn step_relative(&self, index: usize) -> f64 {
+        match self.stepping {
+            Stepping::Relative { step_count: 0 } => 0.0,
+            Stepping::Relative { step_count } => index.min(step_count) as f64 / step_count as f64,
+            Stepping::Absolute { .. } => self.delegate.to_relative(self.step_absolute(index)),
+        }
+    }
+
+    /// The exact absolute value of a step.
+    pub fn step_absolute(&self, index: usize) -> N {
+        match self.stepping {
+            Stepping::Relative { .. } => self.delegate.to_absolute(self.step_relative(index)),
+            Stepping::Absolute {
+                origin,
+                interval,
+                step_count,
+            } => N::from_float(origin + index.min(step_count) as f64 * interval),
+        }
+    }
+
+    fn clamp_index(&self, index: f64) -> usize {
+        index.max(0.0).min(self.step_count() as f64) as usize
+    }
+}
+
+impl<N, S> Scale<N> for SteppedScale<N, S>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.step_relative(self.step_index(absolute))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.step_absolute(self.step_index_of_relative(relative))
+    }
+
+    fn max(&self) -> N {
+        self.delegate.max()
+    }
+
+    fn min(&self) -> N {
+        self.delegate.min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_stepped_relative() {
+        let scale = SteppedScale::new(LinearScale::new(0_f64, 100_f64), 4);
+
+        assert_eq!(scale.step_count(), 4);
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.to_relative(20.0), 0.25);
+        assert_approx_eq!(scale.to_relative(30.0), 0.25);
+        assert_approx_eq!(scale.to_relative(40.0), 0.5);
+        assert_approx_eq!(scale.to_relative(100.0), 1.0);
+        assert_approx_eq!(scale.to_relative(150.0), 1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.19), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.21), 25.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 50.0);
+        assert_approx_eq!(scale.to_absolute(0.79), 75.0);
+        assert_approx_eq!(scale.to_absolute(0.81), 100.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 100.0);
+        assert_approx_eq!(scale.to_absolute(-1.0), 0.0);
+
+        for i in 0..=4 {
+            let relative = scale.step_relative(i);
+            assert_eq!(scale.step_index_of_relative(relative), i);
+            assert_eq!(scale.step_index(scale.to_absolute(relative)), i);
+            assert_approx_eq!(scale.to_relative(scale.to_absolute(relative)), relative);
+        }
+    }
+
+    #[test]
+    fn test_stepped_follows_delegate() {
+        let scale = SteppedScale::new(LogarithmicScale::new(10_f64, 10240_f64), 10);
+
+        assert_approx_eq!(scale.to_absolute(0.5), 320.0);
+        assert_approx_eq!(scale.to_relative(300.0), 0.5);
+        assert_eq!(scale.step_index(300.0), 5);
+        assert_approx_eq!(scale.step_absolute(3), 80.0);
+    }
+
+    #[test]
+    fn test_stepped_interval() {
+        let scale = SteppedScale::with_interval(LinearScale::new(-12_f64, 12_f64), 0.5).unwrap();
+
+        assert_eq!(scale.step_count(), 48);
+        assert_approx_eq!(scale.to_absolute(0.51), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.52), 0.5);
+        assert_approx_eq!(scale.to_relative(0.3), 12.5 / 24.0);
+        assert_eq!(scale.step_index(0.3), 25);
+        assert_approx_eq!(scale.step_absolute(25), 0.5);
+
+        let scale =
+            SteppedScale::with_interval(LogarithmicScale::new(100_f64, 1000_f64), 100.0).unwrap();
+        assert_eq!(scale.step_count(), 9);
+        assert_approx_eq!(scale.to_absolute(0.5), 300.0);
+        assert_approx_eq!(scale.to_relative(290.0), 300f64.log10() - 2.0);
+    }
+
+    #[test]
+    fn test_stepped_converter() {
+        let host: LinearScale<f64> = LinearScale::new(0.0, 1.0);
+        let oversampling = SteppedScale::new(LinearScale::new(0_u32, 3), 3);
+        let conv = (&host, &oversampling);
+
+        assert_eq!(conv.convert(0.0), 0);
+        assert_eq!(conv.convert(0.3), 1);
+        assert_eq!(conv.convert(0.6), 2);
+        assert_eq!(conv.convert(1.0), 3);
+        assert_approx_eq!(conv.convert_back(2), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_stepped_invalid_interval() {
+        let zero = SteppedScale::with_interval(LinearScale::new(0_f64, 1_f64), 0.0);
+        assert_eq!(zero.err(), Some(ScaleError::InvalidInterval));
+
+        let negative = SteppedScale::with_interval(LinearScale::new(0_f64, 1_f64), -0.5);
+        assert_eq!(negative.err(), Some(ScaleError::InvalidInterval));
+    }
+}
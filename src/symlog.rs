@@ -0,0 +1,145 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// A symmetric logarithmic scale that, unlike [`LogarithmicScale`](crate::prelude::LogarithmicScale),
+/// can handle zero and negative values.
+///
+/// Values are transformed by `sign(x) * ln(1 + |x| / threshold)`, so the scale is approximately linear
+/// for values much smaller than the threshold and approximately logarithmic for values much larger than it.
+/// The transformation is continuous, monotonic and invertible across the whole real line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymlogScale<N> {
+    min: N,
+    max: N,
+    threshold: f64,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> SymlogScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Create a new symlog scale. Fails if the threshold is not a finite value greater than zero.
+    pub fn new(min: N, max: N, threshold: f64) -> Result<SymlogScale<N>, ScaleError> {
+        if !threshold.is_finite() || threshold <= 0.0 {
+            return Err(ScaleError::InvalidThreshold);
+        }
+
+        let linear_delegate = LinearScale::new(
+            symlog(min.clone().to_float(), threshold),
+            symlog(max.clone().to_float(), threshold),
+        );
+
+        Ok(SymlogScale {
+            min,
+            max,
+            threshold,
+            linear_delegate,
+        })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl<N> Scale<N> for SymlogScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let abs_log = symlog(absolute.to_float(), self.threshold);
+        self.linear_delegate.to_relative(abs_log)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let abs_log = self.linear_delegate.to_absolute(relative);
+        N::from_float(symexp(abs_log, self.threshold))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+fn symlog(x: f64, threshold: f64) -> f64 {
+    x.signum() * (x.abs() / threshold).ln_1p()
+}
+
+fn symexp(y: f64, threshold: f64) -> f64 {
+    y.signum() * threshold * y.abs().exp_m1()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_symlog() {
+        let scale: SymlogScale<f64> = SymlogScale::new(-1000.0, 1000.0, 1.0).unwrap();
+
+        assert_approx_eq!(scale.to_relative(-1000.0), 0.0);
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(1000.0), 1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), -1000.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 1000.0);
+
+        for x in &[-999.0, -100.0, -1.0, -0.01, 0.01, 1.0, 100.0, 999.0] {
+            assert_approx_eq!(scale.to_relative(*x), 1.0 - scale.to_relative(-x));
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(*x)), *x);
+        }
+    }
+
+    #[test]
+    fn test_symlog_monotonic() {
+        let scale: SymlogScale<f64> = SymlogScale::new(-20.0, 100.0, 0.5).unwrap();
+
+        let mut previous = f64::NEG_INFINITY;
+        for i in -300..=300 {
+            let relative = scale.to_relative(i as f64 / 2.0);
+            assert!(relative > previous);
+            previous = relative;
+        }
+
+        assert_approx_eq!(scale.to_relative(-1e-12), scale.to_relative(1e-12));
+    }
+
+    #[test]
+    fn test_symlog_threshold() {
+        let nearly_linear: SymlogScale<f64> = SymlogScale::new(-1.0, 1.0, 1_000_000.0).unwrap();
+        assert_approx_eq!(nearly_linear.to_relative(0.5), 0.75);
+        assert_approx_eq!(nearly_linear.to_absolute(0.25), -0.5);
+
+        let nearly_log: SymlogScale<f64> = SymlogScale::new(10.0, 10240.0, 0.000_001).unwrap();
+        assert_approx_eq!(nearly_log.to_relative(320.0), 0.5, 1e-3);
+    }
+
+    #[test]
+    fn test_symlog_converter() {
+        let slider: LinearScale<f64> = LinearScale::new(0.0, 100.0);
+        let offset: SymlogScale<f64> = SymlogScale::new(-500.0, 500.0, 10.0).unwrap();
+
+        assert_approx_eq!((&slider, &offset).convert(50.0), 0.0);
+        assert_approx_eq!((&slider, &offset).convert(0.0), -500.0);
+        assert_approx_eq!((&slider, &offset).convert_back(500.0), 100.0);
+    }
+
+    #[test]
+    fn test_symlog_invalid_threshold() {
+        let zero: Result<SymlogScale<f64>, _> = SymlogScale::new(-1.0, 1.0, 0.0);
+        assert_eq!(zero, Err(ScaleError::InvalidThreshold));
+
+        let negative: Result<SymlogScale<f64>, _> = SymlogScale::new(-1.0, 1.0, -1.0);
+        assert_eq!(negative, Err(ScaleError::InvalidThreshold));
+    }
+}
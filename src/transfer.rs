@@ -0,0 +1,329 @@
+use super::convert::*;
+use super::*;
+
+// SMPTE ST 2084 constants
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// The peak luminance of the PQ transfer function in cd/m².
+pub const PQ_PEAK_LUMINANCE: f64 = 10_000.0;
+
+// ITU-R BT.2100 HLG constants
+const HLG_A: f64 = 0.178_832_77;
+const HLG_B: f64 = 1.0 - 4.0 * HLG_A;
+const HLG_C: f64 = 0.559_910_73;
+
+/// The sRGB transfer function (IEC 61966-2-1).
+///
+/// Absolute values are linear light between 0.0 and `peak` (e.g. 1.0 or 80 cd/m²), relative values are
+/// the non-linear signal. Negative values are mirrored, as in extended sRGB.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SrgbScale<N> {
+    min: N,
+    max: N,
+    peak: f64,
+}
+
+impl<N> SrgbScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(peak: N) -> SrgbScale<N> {
+        SrgbScale {
+            min: N::from_float(0.0),
+            peak: peak.clone().to_float(),
+            max: peak,
+        }
+    }
+}
+
+impl<N> Scale<N> for SrgbScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let linear = absolute.to_float() / self.peak;
+        let encoded = if linear.abs() <= 0.003_130_8 {
+            12.92 * linear.abs()
+        } else {
+            1.055 * linear.abs().powf(1.0 / 2.4) - 0.055
+        };
+        linear.signum() * encoded
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let linear = if relative.abs() <= 0.040_45 {
+            relative.abs() / 12.92
+        } else {
+            ((relative.abs() + 0.055) / 1.055).powf(2.4)
+        };
+        N::from_float(relative.signum() * linear * self.peak)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// A pure power law transfer function, i.e. `absolute = peak * relative ^ gamma`.
+///
+/// Negative values are mirrored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GammaScale<N> {
+    min: N,
+    max: N,
+    peak: f64,
+    gamma: f64,
+}
+
+impl<N> GammaScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(peak: N, gamma: f64) -> GammaScale<N> {
+        GammaScale {
+            min: N::from_float(0.0),
+            peak: peak.clone().to_float(),
+            max: peak,
+            gamma,
+        }
+    }
+
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+}
+
+impl<N> Scale<N> for GammaScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let linear = absolute.to_float() / self.peak;
+        linear.signum() * linear.abs().powf(1.0 / self.gamma)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let linear = relative.signum() * relative.abs().powf(self.gamma);
+        N::from_float(linear * self.peak)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// The perceptual quantizer (PQ) transfer function as defined in SMPTE ST 2084.
+///
+/// Absolute values are display luminance in cd/m² (nits) between 0 and 10000, relative values are
+/// the non-linear signal, so [`to_absolute`](Scale::to_absolute) is the EOTF.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PqScale<N> {
+    min: N,
+    max: N,
+}
+
+impl<N> PqScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new() -> PqScale<N> {
+        PqScale {
+            min: N::from_float(0.0),
+            max: N::from_float(PQ_PEAK_LUMINANCE),
+        }
+    }
+}
+
+impl<N> Default for PqScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn default() -> Self {
+        PqScale::new()
+    }
+}
+
+impl<N> Scale<N> for PqScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let y = (absolute.to_float() / PQ_PEAK_LUMINANCE)
+            .max(0.0)
+            .powf(PQ_M1);
+        ((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y)).powf(PQ_M2)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let e = relative.max(0.0).powf(1.0 / PQ_M2);
+        let y = ((e - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * e)).powf(1.0 / PQ_M1);
+        N::from_float(y * PQ_PEAK_LUMINANCE)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+/// The hybrid log-gamma (HLG) transfer function as defined in ITU-R BT.2100.
+///
+/// Absolute values are normalized scene linear light between 0.0 and 1.0, multiplied by `peak`,
+/// relative values are the non-linear signal, so [`to_relative`](Scale::to_relative) is the OETF.
+/// The display side OOTF (system gamma) is not part of this scale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HlgScale<N> {
+    min: N,
+    max: N,
+    peak: f64,
+}
+
+impl<N> HlgScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(peak: N) -> HlgScale<N> {
+        HlgScale {
+            min: N::from_float(0.0),
+            peak: peak.clone().to_float(),
+            max: peak,
+        }
+    }
+}
+
+impl<N> Scale<N> for HlgScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let e = (absolute.to_float() / self.peak).max(0.0);
+        if e <= 1.0 / 12.0 {
+            (3.0 * e).sqrt()
+        } else {
+            HLG_A * (12.0 * e - HLG_B).ln() + HLG_C
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let relative = relative.max(0.0);
+        let e = if relative <= 0.5 {
+            relative * relative / 3.0
+        } else {
+            (((relative - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+        };
+        N::from_float(e * self.peak)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_srgb() {
+        let scale: SrgbScale<f64> = SrgbScale::new(1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.04045), 0.04045 / 12.92);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.214_041_14);
+        assert_approx_eq!(scale.to_absolute(1.0), 1.0);
+
+        assert_approx_eq!(scale.to_relative(0.0031308), 0.040_449_94);
+        assert_approx_eq!(scale.to_relative(0.214_041_14), 0.5);
+        assert_approx_eq!(scale.to_relative(-0.214_041_14), -0.5);
+
+        let nits: SrgbScale<f64> = SrgbScale::new(80.0);
+        let code: LinearScale<u8> = LinearScale::new(0, 255);
+        assert_approx_eq!((&code, &nits).convert(255), 80.0);
+        assert_approx_eq!((&code, &nits).convert(0), 0.0);
+    }
+
+    #[test]
+    fn test_gamma() {
+        let scale: GammaScale<f64> = GammaScale::new(100.0, 2.4);
+
+        assert_approx_eq!(scale.to_absolute(0.5), 100.0 * 0.5f64.powf(2.4));
+        assert_approx_eq!(scale.to_relative(100.0 * 0.5f64.powf(2.4)), 0.5);
+        assert_approx_eq!(scale.to_absolute(1.0), 100.0);
+        assert_approx_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.gamma(), 2.4);
+    }
+
+    #[test]
+    fn test_pq() {
+        let scale: PqScale<f64> = PqScale::new();
+
+        // reference values from SMPTE ST 2084
+        assert_approx_eq!(scale.to_relative(0.0), 0.000_000_73, 1e-8);
+        assert_approx_eq!(scale.to_relative(100.0), 0.508_078_42);
+        assert_approx_eq!(scale.to_relative(1_000.0), 0.751_827_10);
+        assert_approx_eq!(scale.to_relative(10_000.0), 1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.508_078_42), 100.0, 1e-5);
+        assert_approx_eq!(scale.to_absolute(1.0), 10_000.0, 1e-6);
+
+        for nits in &[0.1, 1.0, 203.0, 4_000.0] {
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(*nits)), *nits, 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_pq_code_values() {
+        let code_scale: LinearScale<u16> = LinearScale::new(0, 1023);
+        let pq_scale: PqScale<f64> = PqScale::new();
+        let conv = (&code_scale, &pq_scale);
+
+        assert_approx_eq!(conv.convert(0), 0.0);
+        assert_approx_eq!(conv.convert(520), 100.229_886, 1e-5);
+        assert_approx_eq!(conv.convert(769), 998.932_391, 1e-5);
+        assert_approx_eq!(conv.convert(1023), 10_000.0, 1e-6);
+
+        assert_eq!(conv.convert_back(10_000.0), 1023);
+        assert_eq!(conv.convert_back(conv.convert(600)), 600);
+    }
+
+    #[test]
+    fn test_hlg() {
+        let scale: HlgScale<f64> = HlgScale::new(1.0);
+
+        // reference values from ITU-R BT.2100
+        assert_approx_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.to_relative(1.0 / 12.0), 0.5);
+        assert_approx_eq!(scale.to_relative(0.5), 0.871_643_47);
+        assert_approx_eq!(scale.to_relative(1.0), 1.0);
+
+        assert_approx_eq!(scale.to_absolute(0.5), 1.0 / 12.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 1.0);
+
+        for i in 0..=20 {
+            let e = i as f64 / 20.0;
+            assert_approx_eq!(scale.to_absolute(scale.to_relative(e)), e);
+        }
+    }
+}
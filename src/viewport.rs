@@ -0,0 +1,193 @@
+use super::convert::*;
+use super::*;
+
+/// An adapter that shows a window of another scale, e.g. the zoomed in part of a waveform or spectrum view.
+///
+/// The window is defined in the relative space of the wrapped scale, so zooming into a logarithmic scale keeps it
+/// logarithmic. Relative values of the viewport are relative to the window, i.e. 0.0 and 1.0 are the edges of the
+/// visible area, and [`min`](Scale::min) and [`max`](Scale::max) are the visible absolute values.
+/// The window never leaves the range of the wrapped scale and its span is kept within optional zoom limits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Viewport<S> {
+    inner: S,
+    start: f64,
+    end: f64,
+    min_span: f64,
+    max_span: f64,
+}
+
+impl<S> Viewport<S> {
+    /// Create a viewport that shows the complete range of the wrapped scale.
+    pub fn new(inner: S) -> Viewport<S> {
+        Viewport {
+            inner,
+            start: 0.0,
+            end: 1.0,
+            min_span: f64::EPSILON,
+            max_span: 1.0,
+        }
+    }
+
+    /// Limit the relative span of the window. Spans are given in the relative space of the wrapped scale,
+    /// so a minimum span of 0.01 limits zooming in to a factor of 100.
+    pub fn with_zoom_limits(mut self, min_span: f64, max_span: f64) -> Viewport<S> {
+        self.min_span = min_span.clamp(f64::EPSILON, 1.0);
+        self.max_span = max_span.clamp(self.min_span, 1.0);
+        self.set_window(self.start, self.end);
+        self
+    }
+
+    pub fn with_window(mut self, start: f64, end: f64) -> Viewport<S> {
+        self.set_window(start, end);
+        self
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// The visible window in the relative space of the wrapped scale.
+    pub fn window(&self) -> (f64, f64) {
+        (self.start, self.end)
+    }
+
+    pub fn span(&self) -> f64 {
+        self.end - self.start
+    }
+
+    pub fn zoom_limits(&self) -> (f64, f64) {
+        (self.min_span, self.max_span)
+    }
+
+    /// Show the provided window of the wrapped scale, adjusted to the zoom limits and the range of the wrapped scale.
+    pub fn set_window(&mut self, start: f64, end: f64) {
+        let centre = 0.5 * (start + end);
+        let span = (end - start).abs().clamp(self.min_span, self.max_span);
+        self.place(centre - 0.5 * span, span);
+    }
+
+    /// Show the complete range of the wrapped scale, or as much of it as the zoom limits allow.
+    pub fn reset(&mut self) {
+        self.set_window(0.0, 1.0);
+    }
+
+    /// Move the window by a distance relative to the window, e.g. a delta of 0.5 moves the window by half its span.
+    pub fn pan(&mut self, delta: f64) {
+        let span = self.span();
+        self.place(self.start + delta * span, span);
+    }
+
+    /// Zoom in by a factor (values below 1.0 zoom out), keeping the point at the provided position relative
+    /// to the window in place, as far as the zoom limits and the range of the wrapped scale allow.
+    pub fn zoom(&mut self, factor: f64, around: f64) {
+        let anchor = self.start + around * self.span();
+        let span = (self.span() / factor).clamp(self.min_span, self.max_span);
+        self.place(anchor - around * span, span);
+    }
+
+    fn place(&mut self, start: f64, span: f64) {
+        let span = span.clamp(self.min_span, self.max_span);
+        let start = start.clamp(0.0, 1.0 - span);
+        self.start = start;
+        self.end = start + span;
+    }
+}
+
+impl<N, S> Scale<N> for Viewport<S>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        (self.inner.to_relative(absolute) - self.start) / self.span()
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.inner.to_absolute(self.start + relative * self.span())
+    }
+
+    fn max(&self) -> N {
+        self.inner.to_absolute(self.end)
+    }
+
+    fn min(&self) -> N {
+        self.inner.to_absolute(self.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_viewport() {
+        let mut view = LinearScale::new(0_f64, 100_f64).viewport();
+
+        assert_approx_eq!(view.min(), 0.0);
+        assert_approx_eq!(view.max(), 100.0);
+
+        view.zoom(4.0, 0.5);
+        assert_approx_eq!(view.min(), 37.5);
+        assert_approx_eq!(view.max(), 62.5);
+        assert_approx_eq!(view.to_relative(50.0), 0.5);
+        assert_approx_eq!(view.to_absolute(1.0), 62.5);
+
+        view.pan(1.0);
+        assert_approx_eq!(view.min(), 62.5);
+        assert_approx_eq!(view.max(), 87.5);
+
+        view.pan(10.0);
+        assert_approx_eq!(view.min(), 75.0);
+        assert_approx_eq!(view.max(), 100.0);
+
+        view.zoom(0.1, 0.0);
+        assert_eq!(view.window(), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_viewport_zoom_around() {
+        let mut view = LinearScale::new(0_f64, 100_f64).viewport();
+
+        view.zoom(2.0, 0.2);
+        assert_approx_eq!(view.to_absolute(0.2), 20.0);
+        assert_approx_eq!(view.min(), 10.0);
+        assert_approx_eq!(view.max(), 60.0);
+    }
+
+    #[test]
+    fn test_viewport_keeps_curve() {
+        let view = LogarithmicScale::new(10_f64, 10_000_f64)
+            .viewport()
+            .with_window(1.0 / 3.0, 2.0 / 3.0);
+
+        assert_approx_eq!(view.min(), 100.0);
+        assert_approx_eq!(view.max(), 1_000.0);
+        assert_approx_eq!(view.to_absolute(0.5), 10f64.powf(2.5));
+
+        let pixels: LinearScale<f64> = LinearScale::new(0.0, 800.0);
+        assert_approx_eq!((&pixels, &view).convert_back(10f64.powf(2.5)), 400.0);
+    }
+
+    #[test]
+    fn test_viewport_zoom_limits() {
+        let mut view = LinearScale::new(0_f64, 100_f64)
+            .viewport()
+            .with_zoom_limits(0.1, 0.5);
+
+        assert_approx_eq!(view.span(), 0.5);
+        assert_approx_eq!(view.min(), 25.0);
+
+        view.zoom(100.0, 0.5);
+        assert_approx_eq!(view.span(), 0.1);
+        assert_approx_eq!(view.min(), 45.0);
+
+        view.reset();
+        assert_eq!(view.window(), (0.25, 0.75));
+    }
+}